@@ -0,0 +1,57 @@
+use crate::algorithms::arena::{self, Arena};
+use crate::algorithms::linked_list_stack::{self, LinkedStack};
+
+// Storage for the adjacency lists of all vertices in UGraph and DGraph.
+pub trait Adjacency {
+    type Iter<'a>: Iterator<Item = &'a usize>
+    where
+        Self: 'a;
+
+    fn with_vertices(num_vertices: usize) -> Self;
+
+    fn push(&mut self, v: usize, w: usize);
+
+    fn iter(&self, v: usize) -> Self::Iter<'_>;
+}
+
+// One slab shared by the lists of every vertex, so adding an edge only
+// allocates when the slab grows.
+pub struct ArenaAdjacency {
+    arena: Arena<usize>,
+    heads: Vec<Option<usize>>,
+}
+
+impl Adjacency for Vec<LinkedStack<usize>> {
+    type Iter<'a> = linked_list_stack::Iter<'a, usize>;
+
+    fn with_vertices(num_vertices: usize) -> Self {
+        (0..num_vertices).map(|_| LinkedStack::new()).collect()
+    }
+
+    fn push(&mut self, v: usize, w: usize) {
+        self[v].push(w)
+    }
+
+    fn iter(&self, v: usize) -> Self::Iter<'_> {
+        self[v].iter()
+    }
+}
+
+impl Adjacency for ArenaAdjacency {
+    type Iter<'a> = arena::Iter<'a, usize>;
+
+    fn with_vertices(num_vertices: usize) -> Self {
+        ArenaAdjacency {
+            arena: Arena::new(),
+            heads: vec![None; num_vertices],
+        }
+    }
+
+    fn push(&mut self, v: usize, w: usize) {
+        self.heads[v] = Some(self.arena.alloc(w, self.heads[v]));
+    }
+
+    fn iter(&self, v: usize) -> Self::Iter<'_> {
+        self.arena.iter(self.heads[v])
+    }
+}
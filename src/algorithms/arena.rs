@@ -0,0 +1,180 @@
+use std::marker::PhantomData;
+
+// Slab of index-linked nodes. Released slots are chained into a free list and
+// reused by later allocations, so a collection built on top only allocates
+// when the underlying vector grows. The links are only sound to walk mutably
+// while they form acyclic chains, so the arena stays private to the crate.
+#[derive(Clone)]
+pub(crate) struct Arena<T> {
+    nodes: Vec<Node<T>>,
+    free: Option<usize>,
+}
+
+#[derive(Clone)]
+struct Node<T> {
+    item: Option<T>,
+    next: Option<usize>,
+}
+
+pub struct Iter<'a, T> {
+    nodes: &'a [Node<T>],
+    next: Option<usize>,
+}
+
+pub struct IterMut<'a, T> {
+    nodes: *mut Node<T>,
+    capacity: usize,
+    next: Option<usize>,
+    remaining: usize,
+    marker: PhantomData<&'a mut T>,
+}
+
+impl<T> Arena<T> {
+    pub fn new() -> Self {
+        Arena::with_capacity(0)
+    }
+
+    pub fn with_capacity(capacity: usize) -> Self {
+        Arena {
+            nodes: Vec::with_capacity(capacity),
+            free: None,
+        }
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.nodes.capacity()
+    }
+
+    pub fn alloc(&mut self, item: T, next: Option<usize>) -> usize {
+        let node = Node {
+            item: Some(item),
+            next,
+        };
+
+        match self.free {
+            None => {
+                self.nodes.push(node);
+                self.nodes.len() - 1
+            }
+            Some(index) => {
+                self.free = self.nodes[index].next;
+                self.nodes[index] = node;
+                index
+            }
+        }
+    }
+
+    pub fn release(&mut self, index: usize) -> T {
+        let item = self.nodes[index].item.take().unwrap();
+
+        self.nodes[index].next = self.free;
+        self.free = Some(index);
+
+        item
+    }
+
+    pub fn get(&self, index: usize) -> &T {
+        self.nodes[index].item.as_ref().unwrap()
+    }
+
+    pub fn get_mut(&mut self, index: usize) -> &mut T {
+        self.nodes[index].item.as_mut().unwrap()
+    }
+
+    pub fn next(&self, index: usize) -> Option<usize> {
+        self.nodes[index].next
+    }
+
+    pub fn set_next(&mut self, index: usize, next: Option<usize>) {
+        self.nodes[index].next = next;
+    }
+
+    pub fn clear(&mut self) {
+        self.nodes.clear();
+        self.free = None;
+    }
+
+    pub fn iter(&self, head: Option<usize>) -> Iter<'_, T> {
+        Iter {
+            nodes: &self.nodes,
+            next: head,
+        }
+    }
+
+    // Walks at most `len` nodes from `head`. The chain must not revisit a
+    // node within those `len` steps, which is what makes handing out one
+    // mutable reference per node sound.
+    pub fn iter_mut(&mut self, head: Option<usize>, len: usize) -> IterMut<'_, T> {
+        IterMut {
+            nodes: self.nodes.as_mut_ptr(),
+            capacity: self.nodes.len(),
+            next: head,
+            remaining: len,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<T> Default for Arena<T> {
+    fn default() -> Self {
+        Arena::new()
+    }
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next.map(|index| {
+            let node = &self.nodes[index];
+            self.next = node.next;
+            node.item.as_ref().unwrap()
+        })
+    }
+}
+
+impl<'a, T> Iterator for IterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        self.next.map(|index| {
+            assert!(index < self.capacity, "arena index out of bounds");
+
+            let node = unsafe { &mut *self.nodes.add(index) };
+            self.next = node.next;
+            self.remaining -= 1;
+            node.item.as_mut().unwrap()
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::algorithms::arena::Arena;
+
+    #[test]
+    fn iter_mut_should_stop_after_len_nodes_even_on_a_cycle() {
+        let mut arena = Arena::new();
+        let index = arena.alloc(1, None);
+
+        arena.set_next(index, Some(index));
+
+        let items: Vec<&mut i32> = arena.iter_mut(Some(index), 1).collect();
+
+        assert!(items == vec![&mut 1]);
+        assert!(arena.iter_mut(Some(index), 0).next().is_none());
+    }
+
+    #[test]
+    #[should_panic]
+    fn iter_mut_should_panic_on_an_index_outside_the_arena() {
+        let mut arena = Arena::<i32>::new();
+
+        arena.alloc(1, None);
+        arena.iter_mut(Some(100000), 1).next();
+    }
+}
@@ -0,0 +1,269 @@
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::iter::FromIterator;
+
+use crate::algorithms::arena::Arena;
+pub use crate::algorithms::arena::{Iter, IterMut};
+
+#[derive(Clone)]
+pub struct ArenaQueue<T> {
+    arena: Arena<T>,
+    head: Option<usize>,
+    tail: Option<usize>,
+    len: usize,
+}
+
+pub struct IntoIter<T>(ArenaQueue<T>);
+
+impl<T> ArenaQueue<T> {
+    pub fn new() -> Self {
+        ArenaQueue::with_capacity(0)
+    }
+
+    pub fn with_capacity(capacity: usize) -> Self {
+        ArenaQueue {
+            arena: Arena::with_capacity(capacity),
+            head: None,
+            tail: None,
+            len: 0,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.head.is_none()
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.arena.capacity()
+    }
+
+    pub fn enqueue(&mut self, item: T) {
+        let new_tail = self.arena.alloc(item, None);
+
+        match self.tail {
+            None => self.head = Some(new_tail),
+            Some(tail) => self.arena.set_next(tail, Some(new_tail)),
+        }
+
+        self.tail = Some(new_tail);
+        self.len += 1;
+    }
+
+    pub fn dequeue(&mut self) -> Option<T> {
+        self.head.map(|head| {
+            self.head = self.arena.next(head);
+
+            if self.head.is_none() {
+                self.tail = None;
+            }
+
+            self.len -= 1;
+            self.arena.release(head)
+        })
+    }
+
+    pub fn peek(&self) -> Option<&T> {
+        self.head.map(|head| self.arena.get(head))
+    }
+
+    pub fn peek_mut(&mut self) -> Option<&mut T> {
+        match self.head {
+            None => None,
+            Some(head) => Some(self.arena.get_mut(head)),
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.arena.clear();
+        self.head = None;
+        self.tail = None;
+        self.len = 0;
+    }
+
+    pub fn iter(&self) -> Iter<'_, T> {
+        self.arena.iter(self.head)
+    }
+
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        self.arena.iter_mut(self.head, self.len)
+    }
+}
+
+impl<T> Default for ArenaQueue<T> {
+    fn default() -> Self {
+        ArenaQueue::new()
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for ArenaQueue<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T: PartialEq> PartialEq for ArenaQueue<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len() == other.len() && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: Eq> Eq for ArenaQueue<T> {}
+
+impl<T: Hash> Hash for ArenaQueue<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.len().hash(state);
+
+        for item in self.iter() {
+            item.hash(state);
+        }
+    }
+}
+
+impl<T> FromIterator<T> for ArenaQueue<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut queue = ArenaQueue::new();
+        queue.extend(iter);
+
+        queue
+    }
+}
+
+impl<T> Extend<T> for ArenaQueue<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for item in iter {
+            self.enqueue(item);
+        }
+    }
+}
+
+impl<T> IntoIterator for ArenaQueue<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter(self)
+    }
+}
+
+impl<'a, T> IntoIterator for &'a ArenaQueue<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut ArenaQueue<T> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+impl<T> Iterator for IntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.dequeue()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::algorithms::arena_queue::ArenaQueue;
+
+    #[test]
+    fn interface_operations_should_work_as_expected() {
+        let mut queue = ArenaQueue::new();
+
+        assert!(queue.is_empty());
+        assert!(queue.dequeue() == None);
+
+        queue.enqueue(0);
+
+        assert!(queue.dequeue() == Some(0));
+
+        for i in 1..5 {
+            queue.enqueue(i);
+        }
+
+        assert!(queue.len() == 4);
+        assert!(queue.peek() == Some(&1));
+
+        for i in 1..5 {
+            assert!(queue.dequeue() == Some(i));
+        }
+
+        assert!(queue.is_empty());
+
+        for i in 0..1000 {
+            queue.enqueue(i);
+
+            if i % 2 == 0 {
+                queue.dequeue();
+            }
+        }
+
+        for i in 500..1000 {
+            assert!(queue.dequeue() == Some(i));
+        }
+
+        assert!(queue.is_empty());
+        assert!(queue.len() == 0);
+    }
+
+    #[test]
+    fn released_slots_should_be_reused() {
+        let mut queue = ArenaQueue::with_capacity(8);
+
+        for i in 0..1000 {
+            queue.enqueue(i);
+
+            if queue.len() == 8 {
+                assert!(queue.dequeue() == Some(i - 7));
+            }
+        }
+
+        assert!(queue.capacity() == 8);
+    }
+
+    #[test]
+    fn iterators_should_traverse_from_front_to_back() {
+        let mut queue: ArenaQueue<u32> = (0..25).collect();
+
+        let mut k = 0;
+
+        for v in &queue {
+            assert!(*v == k);
+
+            k += 1;
+        }
+
+        for v in &mut queue {
+            *v *= 2;
+        }
+
+        let copy = queue.clone();
+
+        assert_eq!(queue, copy);
+
+        queue.clear();
+
+        assert!(queue.is_empty());
+
+        k = 0;
+
+        for v in copy {
+            assert!(v == 2 * k);
+
+            k += 1;
+        }
+    }
+}
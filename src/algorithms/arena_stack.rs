@@ -0,0 +1,250 @@
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::iter::FromIterator;
+
+use crate::algorithms::arena::Arena;
+pub use crate::algorithms::arena::{Iter, IterMut};
+
+#[derive(Clone)]
+pub struct ArenaStack<T> {
+    arena: Arena<T>,
+    head: Option<usize>,
+    len: usize,
+}
+
+pub struct ConsumerIter<T>(ArenaStack<T>);
+
+impl<T> ArenaStack<T> {
+    pub fn new() -> Self {
+        ArenaStack::with_capacity(0)
+    }
+
+    pub fn with_capacity(capacity: usize) -> Self {
+        ArenaStack {
+            arena: Arena::with_capacity(capacity),
+            head: None,
+            len: 0,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.head.is_none()
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.arena.capacity()
+    }
+
+    pub fn push(&mut self, item: T) {
+        self.head = Some(self.arena.alloc(item, self.head));
+        self.len += 1;
+    }
+
+    pub fn pop(&mut self) -> Option<T> {
+        self.head.map(|head| {
+            self.head = self.arena.next(head);
+            self.len -= 1;
+            self.arena.release(head)
+        })
+    }
+
+    pub fn peek(&self) -> Option<&T> {
+        self.head.map(|head| self.arena.get(head))
+    }
+
+    pub fn peek_mut(&mut self) -> Option<&mut T> {
+        match self.head {
+            None => None,
+            Some(head) => Some(self.arena.get_mut(head)),
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.arena.clear();
+        self.head = None;
+        self.len = 0;
+    }
+
+    pub fn iter(&self) -> Iter<'_, T> {
+        self.arena.iter(self.head)
+    }
+
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        self.arena.iter_mut(self.head, self.len)
+    }
+}
+
+impl<T> Default for ArenaStack<T> {
+    fn default() -> Self {
+        ArenaStack::new()
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for ArenaStack<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T: PartialEq> PartialEq for ArenaStack<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len() == other.len() && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: Eq> Eq for ArenaStack<T> {}
+
+impl<T: Hash> Hash for ArenaStack<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.len().hash(state);
+
+        for item in self.iter() {
+            item.hash(state);
+        }
+    }
+}
+
+impl<T> FromIterator<T> for ArenaStack<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut stack = ArenaStack::new();
+        stack.extend(iter);
+
+        stack
+    }
+}
+
+impl<T> Extend<T> for ArenaStack<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for item in iter {
+            self.push(item);
+        }
+    }
+}
+
+impl<T> IntoIterator for ArenaStack<T> {
+    type Item = T;
+    type IntoIter = ConsumerIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        ConsumerIter(self)
+    }
+}
+
+impl<'a, T> IntoIterator for &'a ArenaStack<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut ArenaStack<T> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+impl<T> Iterator for ConsumerIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.pop()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::algorithms::arena_stack::ArenaStack;
+
+    #[test]
+    fn interface_operations_should_work_as_expected() {
+        let mut stack = ArenaStack::<u32>::new();
+
+        assert!(stack.is_empty());
+        assert!(stack.pop() == None);
+        assert!(stack.peek() == None);
+
+        stack.push(0);
+
+        assert!(stack.pop() == Some(0));
+
+        stack.push(1);
+        stack.push(2);
+        stack.push(3);
+
+        assert!(stack.len() == 3);
+        assert!(stack.peek() == Some(&3));
+
+        *stack.peek_mut().unwrap() = 30;
+
+        assert!(stack.pop() == Some(30));
+        assert!(stack.pop() == Some(2));
+        assert!(stack.pop() == Some(1));
+        assert!(stack.pop() == None);
+        assert!(stack.is_empty());
+
+        stack.push(4);
+        stack.clear();
+
+        assert!(stack.is_empty());
+        assert!(stack.len() == 0);
+    }
+
+    #[test]
+    fn released_slots_should_be_reused() {
+        let mut stack = ArenaStack::with_capacity(16);
+
+        for round in 0..100 {
+            for i in 0..16 {
+                stack.push(round * 16 + i);
+            }
+
+            for i in 0..16 {
+                assert!(stack.pop() == Some(round * 16 + 15 - i));
+            }
+        }
+
+        assert!(stack.capacity() == 16);
+    }
+
+    #[test]
+    fn iterators_should_traverse_from_top_to_bottom() {
+        let mut stack: ArenaStack<u32> = (0..25).collect();
+
+        let mut k = 25;
+
+        for v in &stack {
+            k -= 1;
+
+            assert!(*v == k);
+        }
+
+        for v in &mut stack {
+            *v *= 2;
+        }
+
+        let copy = stack.clone();
+
+        assert_eq!(stack, copy);
+        assert_eq!(
+            format!("{:?}", stack.iter().take(3).collect::<Vec<_>>()),
+            "[48, 46, 44]"
+        );
+
+        k = 25;
+
+        for v in stack {
+            k -= 1;
+
+            assert!(v == 2 * k);
+        }
+    }
+}
@@ -1,3 +1,6 @@
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::iter::{FromIterator, Rev};
 use std::slice::IterMut as IterMutVec;
 
 pub struct ArrayStack<T> {
@@ -12,11 +15,15 @@ pub struct Iter<'a, T> {
     next: usize,
 }
 
-pub struct IterMut<'a, T>(IterMutVec<'a, Option<T>>);
+pub struct IterMut<'a, T>(Rev<IterMutVec<'a, Option<T>>>);
 
 impl<T> ArrayStack<T> {
     pub fn new() -> Self {
-        let container = Vec::with_capacity(1);
+        ArrayStack::with_capacity(1)
+    }
+
+    pub fn with_capacity(capacity: usize) -> Self {
+        let container = Vec::with_capacity(capacity.max(1));
 
         ArrayStack {
             container,
@@ -28,6 +35,31 @@ impl<T> ArrayStack<T> {
         self.current == 0
     }
 
+    pub fn len(&self) -> usize {
+        self.current
+    }
+
+    pub fn peek(&self) -> Option<&T> {
+        if self.current == 0 {
+            None
+        } else {
+            self.container[self.current - 1].as_ref()
+        }
+    }
+
+    pub fn peek_mut(&mut self) -> Option<&mut T> {
+        if self.current == 0 {
+            None
+        } else {
+            self.container[self.current - 1].as_mut()
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.container.clear();
+        self.current = 0;
+    }
+
     pub fn push(&mut self, item: T) {
         let capacity = self.container.capacity();
         if self.current == capacity {
@@ -55,10 +87,6 @@ impl<T> ArrayStack<T> {
         }
     }
 
-    pub fn into_iter(self) -> ConsumerIter<T> {
-        ConsumerIter(self)
-    }
-
     pub fn iter(&self) -> Iter<'_, T> {
         Iter {
             container: &self.container,
@@ -67,7 +95,7 @@ impl<T> ArrayStack<T> {
     }
 
     pub fn iter_mut(&mut self) -> IterMut<'_, T> {
-        IterMut(self.container.iter_mut())
+        IterMut(self.container[..self.current].iter_mut().rev())
     }
 
     fn resize(&mut self, capacity: usize) {
@@ -89,6 +117,95 @@ fn insert<T>(v: &mut Vec<Option<T>>, i: usize, item: Option<T>) {
     }
 }
 
+impl<T> Default for ArrayStack<T> {
+    fn default() -> Self {
+        ArrayStack::new()
+    }
+}
+
+impl<T: Clone> Clone for ArrayStack<T> {
+    fn clone(&self) -> Self {
+        let mut container = Vec::with_capacity(self.container.capacity());
+
+        for i in 0..self.current {
+            container.push(self.container[i].clone());
+        }
+
+        ArrayStack {
+            container,
+            current: self.current,
+        }
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for ArrayStack<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T: PartialEq> PartialEq for ArrayStack<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len() == other.len() && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: Eq> Eq for ArrayStack<T> {}
+
+impl<T: Hash> Hash for ArrayStack<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.len().hash(state);
+
+        for item in self.iter() {
+            item.hash(state);
+        }
+    }
+}
+
+impl<T> FromIterator<T> for ArrayStack<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut stack = ArrayStack::new();
+        stack.extend(iter);
+
+        stack
+    }
+}
+
+impl<T> Extend<T> for ArrayStack<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for item in iter {
+            self.push(item);
+        }
+    }
+}
+
+impl<T> IntoIterator for ArrayStack<T> {
+    type Item = T;
+    type IntoIter = ConsumerIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        ConsumerIter(self)
+    }
+}
+
+impl<'a, T> IntoIterator for &'a ArrayStack<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut ArrayStack<T> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
 impl<T> Iterator for ConsumerIter<T> {
     type Item = T;
 
@@ -229,4 +346,77 @@ mod tests {
             assert!(*v == 0);
         }
     }
+
+    #[test]
+    fn peek_len_and_clear_should_work_as_expected() {
+        let mut stack = ArrayStack::<u32>::with_capacity(8);
+
+        assert!(stack.len() == 0);
+        assert!(stack.peek() == None);
+        assert!(stack.peek_mut() == None);
+
+        for i in 0..10 {
+            stack.push(i);
+        }
+
+        assert!(stack.len() == 10);
+        assert!(stack.peek() == Some(&9));
+
+        *stack.peek_mut().unwrap() = 100;
+
+        assert!(stack.pop() == Some(100));
+        assert!(stack.len() == 9);
+
+        stack.clear();
+
+        assert!(stack.is_empty());
+        assert!(stack.peek() == None);
+
+        stack.push(1);
+
+        assert!(stack.pop() == Some(1));
+    }
+
+    #[test]
+    fn standard_traits_should_be_implemented() {
+        use std::collections::hash_map::DefaultHasher;
+        use std::hash::{Hash, Hasher};
+
+        let mut stack: ArrayStack<u32> = (0..5).collect();
+
+        assert_eq!(format!("{:?}", stack), "[4, 3, 2, 1, 0]");
+
+        let copy = stack.clone();
+
+        assert_eq!(stack, copy);
+        assert_eq!(ArrayStack::<u32>::default(), ArrayStack::new());
+
+        let mut h0 = DefaultHasher::new();
+        let mut h1 = DefaultHasher::new();
+        stack.hash(&mut h0);
+        copy.hash(&mut h1);
+
+        assert!(h0.finish() == h1.finish());
+
+        stack.extend(vec![5, 6]);
+
+        assert!(stack != copy);
+        assert!(stack.len() == 7);
+
+        for v in &mut stack {
+            *v += 1;
+        }
+
+        let mut k = 7;
+
+        for v in &stack {
+            assert!(*v == k);
+
+            k -= 1;
+        }
+
+        let items: Vec<u32> = stack.into_iter().collect();
+
+        assert!(items == vec![7, 6, 5, 4, 3, 2, 1]);
+    }
 }
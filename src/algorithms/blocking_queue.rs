@@ -0,0 +1,320 @@
+use std::sync::{Condvar, Mutex, MutexGuard};
+use std::time::Duration;
+
+use crate::algorithms::linked_list_queue::LinkedQueue;
+
+pub struct BlockingQueue<T> {
+    state: Mutex<State<T>>,
+    not_empty: Condvar,
+    not_full: Condvar,
+    capacity: Option<usize>,
+}
+
+struct State<T> {
+    queue: LinkedQueue<T>,
+    closed: bool,
+}
+
+pub struct Drain<'a, T>(&'a BlockingQueue<T>);
+
+impl<T> BlockingQueue<T> {
+    pub fn new() -> Self {
+        BlockingQueue::with_bound(None)
+    }
+
+    pub fn bounded(capacity: usize) -> Self {
+        assert!(capacity > 0, "capacity must be positive");
+
+        BlockingQueue::with_bound(Some(capacity))
+    }
+
+    fn with_bound(capacity: Option<usize>) -> Self {
+        BlockingQueue {
+            state: Mutex::new(State {
+                queue: LinkedQueue::new(),
+                closed: false,
+            }),
+            not_empty: Condvar::new(),
+            not_full: Condvar::new(),
+            capacity,
+        }
+    }
+
+    pub fn capacity(&self) -> Option<usize> {
+        self.capacity
+    }
+
+    pub fn len(&self) -> usize {
+        self.state.lock().unwrap().queue.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.state.lock().unwrap().queue.is_empty()
+    }
+
+    pub fn is_closed(&self) -> bool {
+        self.state.lock().unwrap().closed
+    }
+
+    pub fn close(&self) {
+        self.state.lock().unwrap().closed = true;
+
+        self.not_empty.notify_all();
+        self.not_full.notify_all();
+    }
+
+    // Blocks while the queue is full. Gives the item back if the queue has
+    // been closed.
+    pub fn enqueue(&self, item: T) -> Result<(), T> {
+        let state = self.state.lock().unwrap();
+        let mut state = self
+            .not_full
+            .wait_while(state, |s| !s.closed && self.is_full(s))
+            .unwrap();
+
+        if state.closed {
+            return Err(item);
+        }
+
+        state.queue.enqueue(item);
+        self.not_empty.notify_one();
+
+        Ok(())
+    }
+
+    pub fn try_enqueue(&self, item: T) -> Result<(), T> {
+        let mut state = self.state.lock().unwrap();
+
+        if state.closed || self.is_full(&state) {
+            return Err(item);
+        }
+
+        state.queue.enqueue(item);
+        self.not_empty.notify_one();
+
+        Ok(())
+    }
+
+    // Blocks while the queue is empty. Returns None once the queue has been
+    // closed and every pending item has been taken.
+    pub fn dequeue(&self) -> Option<T> {
+        let state = self.state.lock().unwrap();
+        let state = self
+            .not_empty
+            .wait_while(state, |s| !s.closed && s.queue.is_empty())
+            .unwrap();
+
+        self.take(state)
+    }
+
+    pub fn dequeue_timeout(&self, timeout: Duration) -> Option<T> {
+        let state = self.state.lock().unwrap();
+        let (state, _) = self
+            .not_empty
+            .wait_timeout_while(state, timeout, |s| !s.closed && s.queue.is_empty())
+            .unwrap();
+
+        self.take(state)
+    }
+
+    pub fn try_dequeue(&self) -> Option<T> {
+        let state = self.state.lock().unwrap();
+
+        self.take(state)
+    }
+
+    pub fn drain(&self) -> Drain<'_, T> {
+        Drain(self)
+    }
+
+    fn take(&self, mut state: MutexGuard<'_, State<T>>) -> Option<T> {
+        let item = state.queue.dequeue();
+
+        if item.is_some() {
+            self.not_full.notify_one();
+        }
+
+        item
+    }
+
+    fn is_full(&self, state: &State<T>) -> bool {
+        match self.capacity {
+            None => false,
+            Some(capacity) => state.queue.len() >= capacity,
+        }
+    }
+}
+
+impl<T> Default for BlockingQueue<T> {
+    fn default() -> Self {
+        BlockingQueue::new()
+    }
+}
+
+impl<'a, T> Iterator for Drain<'a, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.dequeue()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a BlockingQueue<T> {
+    type Item = T;
+    type IntoIter = Drain<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.drain()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::algorithms::blocking_queue::BlockingQueue;
+    use std::sync::Arc;
+    use std::thread;
+    use std::time::Duration;
+
+    #[test]
+    fn interface_operations_should_work_as_expected() {
+        let queue = BlockingQueue::new();
+
+        assert!(queue.is_empty());
+        assert!(queue.try_dequeue() == None);
+
+        for i in 0..100 {
+            assert!(queue.enqueue(i).is_ok());
+        }
+
+        assert!(queue.len() == 100);
+
+        for i in 0..100 {
+            assert!(queue.dequeue() == Some(i));
+        }
+
+        assert!(queue.is_empty());
+        assert!(queue.dequeue_timeout(Duration::from_millis(10)) == None);
+        assert!(!queue.is_closed());
+
+        queue.enqueue(1).unwrap();
+        queue.enqueue(2).unwrap();
+        queue.close();
+
+        assert!(queue.is_closed());
+        assert!(queue.enqueue(3) == Err(3));
+        assert!(queue.dequeue() == Some(1));
+        assert!(queue.dequeue() == Some(2));
+        assert!(queue.dequeue() == None);
+    }
+
+    #[test]
+    fn bounded_queue_should_block_producers_when_full() {
+        let queue = Arc::new(BlockingQueue::bounded(2));
+
+        queue.enqueue(0).unwrap();
+        queue.enqueue(1).unwrap();
+
+        assert!(queue.try_enqueue(2) == Err(2));
+
+        let producer = {
+            let queue = Arc::clone(&queue);
+            thread::spawn(move || {
+                for i in 2..50 {
+                    queue.enqueue(i).unwrap();
+                }
+                queue.close();
+            })
+        };
+
+        let mut k = 0;
+
+        for v in queue.drain() {
+            assert!(v == k);
+            assert!(queue.len() <= 2);
+
+            k += 1;
+        }
+
+        producer.join().unwrap();
+
+        assert!(k == 50);
+    }
+
+    #[test]
+    fn close_should_wake_every_waiting_consumer() {
+        let queue = Arc::new(BlockingQueue::<u32>::new());
+
+        let consumers: Vec<_> = (0..4)
+            .map(|_| {
+                let queue = Arc::clone(&queue);
+                thread::spawn(move || queue.dequeue())
+            })
+            .collect();
+
+        thread::sleep(Duration::from_millis(20));
+        queue.close();
+
+        for c in consumers {
+            assert!(c.join().unwrap() == None);
+        }
+    }
+
+    #[test]
+    fn dequeue_timeout_should_return_items_enqueued_while_waiting() {
+        let queue = Arc::new(BlockingQueue::new());
+
+        let producer = {
+            let queue = Arc::clone(&queue);
+            thread::spawn(move || {
+                thread::sleep(Duration::from_millis(20));
+                queue.enqueue(7).unwrap();
+            })
+        };
+
+        assert!(queue.dequeue_timeout(Duration::from_secs(10)) == Some(7));
+
+        producer.join().unwrap();
+    }
+
+    #[test]
+    fn multiple_producers_and_consumers_should_not_lose_jobs() {
+        let num_producers = 4;
+        let per_producer = 2500;
+        let queue = Arc::new(BlockingQueue::bounded(16));
+
+        let producers: Vec<_> = (0..num_producers)
+            .map(|p| {
+                let queue = Arc::clone(&queue);
+                thread::spawn(move || {
+                    for i in 0..per_producer {
+                        queue.enqueue(p * per_producer + i).unwrap();
+                    }
+                })
+            })
+            .collect();
+
+        let consumers: Vec<_> = (0..4)
+            .map(|_| {
+                let queue = Arc::clone(&queue);
+                thread::spawn(move || queue.drain().collect::<Vec<usize>>())
+            })
+            .collect();
+
+        for p in producers {
+            p.join().unwrap();
+        }
+
+        queue.close();
+
+        let mut seen = vec![false; num_producers * per_producer];
+
+        for c in consumers {
+            for v in c.join().unwrap() {
+                assert!(!seen[v]);
+                seen[v] = true;
+            }
+        }
+
+        assert!(seen.iter().all(|s| *s));
+    }
+}
@@ -0,0 +1,360 @@
+use std::cell::UnsafeCell;
+use std::mem::ManuallyDrop;
+use std::ptr;
+use std::sync::atomic::Ordering::{Acquire, Relaxed, Release, SeqCst};
+use std::sync::atomic::{AtomicBool, AtomicPtr};
+
+const RECLAIM_THRESHOLD: usize = 64;
+
+// Treiber stack. Popped nodes are reclaimed through hazard pointers: a thread
+// publishes the node it is about to dereference, and retired nodes are only
+// freed once no published hazard points to them.
+pub struct ConcurrentStack<T> {
+    head: AtomicPtr<Node<T>>,
+    records: AtomicPtr<HazardRecord<T>>,
+}
+
+struct Node<T> {
+    item: ManuallyDrop<T>,
+    next: *mut Node<T>,
+}
+
+struct HazardRecord<T> {
+    hazard: AtomicPtr<Node<T>>,
+    active: AtomicBool,
+    next: *mut HazardRecord<T>,
+    // Only ever touched by the thread that currently owns the record.
+    retired: UnsafeCell<Vec<*mut Node<T>>>,
+}
+
+struct HazardGuard<'a, T>(&'a HazardRecord<T>);
+
+unsafe impl<T: Send> Send for ConcurrentStack<T> {}
+unsafe impl<T: Send> Sync for ConcurrentStack<T> {}
+
+impl<T> ConcurrentStack<T> {
+    pub fn new() -> Self {
+        ConcurrentStack {
+            head: AtomicPtr::new(ptr::null_mut()),
+            records: AtomicPtr::new(ptr::null_mut()),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.head.load(Acquire).is_null()
+    }
+
+    pub fn push(&self, item: T) {
+        let new_node = Box::into_raw(Box::new(Node {
+            item: ManuallyDrop::new(item),
+            next: ptr::null_mut(),
+        }));
+
+        loop {
+            let head = self.head.load(Relaxed);
+
+            unsafe {
+                (*new_node).next = head;
+            }
+
+            if self
+                .head
+                .compare_exchange_weak(head, new_node, Release, Relaxed)
+                .is_ok()
+            {
+                return;
+            }
+        }
+    }
+
+    pub fn pop(&self) -> Option<T> {
+        let guard = self.acquire_record();
+
+        let node = loop {
+            let head = self.head.load(SeqCst);
+
+            if head.is_null() {
+                return None;
+            }
+
+            // Publish the hazard and make sure head did not change meanwhile,
+            // otherwise the node could already have been reclaimed.
+            guard.0.hazard.store(head, SeqCst);
+            if self.head.load(SeqCst) != head {
+                continue;
+            }
+
+            let next = unsafe { (*head).next };
+
+            if self
+                .head
+                .compare_exchange(head, next, SeqCst, SeqCst)
+                .is_ok()
+            {
+                break head;
+            }
+        };
+
+        guard.0.hazard.store(ptr::null_mut(), SeqCst);
+
+        let item = unsafe { ptr::read(&*(*node).item) };
+        self.retire(&guard, node);
+
+        Some(item)
+    }
+
+    fn acquire_record(&self) -> HazardGuard<'_, T> {
+        let mut current = self.records.load(Acquire);
+
+        while !current.is_null() {
+            let record = unsafe { &*current };
+
+            if !record.active.load(Relaxed)
+                && record
+                    .active
+                    .compare_exchange(false, true, Acquire, Relaxed)
+                    .is_ok()
+            {
+                return HazardGuard(record);
+            }
+
+            current = record.next;
+        }
+
+        let new_record = Box::into_raw(Box::new(HazardRecord {
+            hazard: AtomicPtr::new(ptr::null_mut()),
+            active: AtomicBool::new(true),
+            next: ptr::null_mut(),
+            retired: UnsafeCell::new(Vec::new()),
+        }));
+
+        loop {
+            let head = self.records.load(Relaxed);
+
+            unsafe {
+                (*new_record).next = head;
+            }
+
+            if self
+                .records
+                .compare_exchange_weak(head, new_record, Release, Relaxed)
+                .is_ok()
+            {
+                return HazardGuard(unsafe { &*new_record });
+            }
+        }
+    }
+
+    fn retire(&self, guard: &HazardGuard<'_, T>, node: *mut Node<T>) {
+        let retired = unsafe { &mut *guard.0.retired.get() };
+
+        retired.push(node);
+
+        if retired.len() >= RECLAIM_THRESHOLD {
+            let hazards = self.hazards();
+
+            retired.retain(|node| {
+                if hazards.contains(node) {
+                    true
+                } else {
+                    unsafe {
+                        drop(Box::from_raw(*node));
+                    }
+                    false
+                }
+            });
+        }
+    }
+
+    fn hazards(&self) -> Vec<*mut Node<T>> {
+        let mut hazards = Vec::new();
+        let mut current = self.records.load(Acquire);
+
+        while !current.is_null() {
+            let record = unsafe { &*current };
+            let hazard = record.hazard.load(SeqCst);
+
+            if !hazard.is_null() {
+                hazards.push(hazard);
+            }
+
+            current = record.next;
+        }
+
+        hazards
+    }
+}
+
+impl<'a, T> Drop for HazardGuard<'a, T> {
+    fn drop(&mut self) {
+        self.0.hazard.store(ptr::null_mut(), SeqCst);
+        self.0.active.store(false, Release);
+    }
+}
+
+impl<T> Default for ConcurrentStack<T> {
+    fn default() -> Self {
+        ConcurrentStack::new()
+    }
+}
+
+impl<T> Drop for ConcurrentStack<T> {
+    fn drop(&mut self) {
+        let mut current = *self.head.get_mut();
+        while !current.is_null() {
+            let mut node = unsafe { Box::from_raw(current) };
+            unsafe {
+                ManuallyDrop::drop(&mut node.item);
+            }
+            current = node.next;
+        }
+
+        let mut current = *self.records.get_mut();
+        while !current.is_null() {
+            let record = unsafe { Box::from_raw(current) };
+            for node in record.retired.into_inner() {
+                unsafe {
+                    drop(Box::from_raw(node));
+                }
+            }
+            current = record.next;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::algorithms::concurrent_stack::ConcurrentStack;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use std::thread;
+
+    #[test]
+    fn interface_operations_should_work_as_expected() {
+        let stack = ConcurrentStack::<u32>::new();
+
+        assert!(stack.is_empty());
+        assert!(stack.pop() == None);
+
+        stack.push(0);
+
+        assert!(stack.pop() == Some(0));
+
+        stack.push(1);
+        stack.push(2);
+        stack.push(3);
+
+        assert!(stack.pop() == Some(3));
+        assert!(stack.pop() == Some(2));
+        assert!(stack.pop() == Some(1));
+        assert!(stack.pop() == None);
+        assert!(stack.is_empty());
+
+        for i in 0..1000 {
+            stack.push(i);
+        }
+
+        for i in 0..1000 {
+            assert!(stack.pop() == Some(999 - i));
+        }
+
+        assert!(stack.is_empty());
+    }
+
+    #[test]
+    fn concurrent_pushes_and_pops_should_not_lose_items() {
+        let num_threads = 8;
+        let per_thread = 5000;
+        let stack = Arc::new(ConcurrentStack::new());
+
+        let producers: Vec<_> = (0..num_threads)
+            .map(|t| {
+                let stack = Arc::clone(&stack);
+                thread::spawn(move || {
+                    for i in 0..per_thread {
+                        stack.push(t * per_thread + i);
+                    }
+                })
+            })
+            .collect();
+
+        let consumers: Vec<_> = (0..num_threads)
+            .map(|_| {
+                let stack = Arc::clone(&stack);
+                thread::spawn(move || {
+                    let mut popped = Vec::new();
+                    for _ in 0..per_thread {
+                        if let Some(v) = stack.pop() {
+                            popped.push(v);
+                        }
+                    }
+                    popped
+                })
+            })
+            .collect();
+
+        for p in producers {
+            p.join().unwrap();
+        }
+
+        let mut seen = vec![false; num_threads * per_thread];
+
+        for c in consumers {
+            for v in c.join().unwrap() {
+                assert!(!seen[v]);
+                seen[v] = true;
+            }
+        }
+
+        while let Some(v) = stack.pop() {
+            assert!(!seen[v]);
+            seen[v] = true;
+        }
+
+        assert!(seen.iter().all(|s| *s));
+    }
+
+    #[test]
+    fn concurrent_pushes_and_pops_should_drop_every_item_exactly_once() {
+        struct Counted(Arc<AtomicUsize>);
+
+        impl Drop for Counted {
+            fn drop(&mut self) {
+                self.0.fetch_add(1, Ordering::SeqCst);
+            }
+        }
+
+        let drops = Arc::new(AtomicUsize::new(0));
+        let stack = Arc::new(ConcurrentStack::new());
+
+        let workers: Vec<_> = (0..8)
+            .map(|_| {
+                let stack = Arc::clone(&stack);
+                let drops = Arc::clone(&drops);
+                thread::spawn(move || {
+                    for i in 0..2000 {
+                        stack.push(Counted(Arc::clone(&drops)));
+                        if i % 3 != 0 {
+                            stack.pop();
+                        }
+                    }
+                })
+            })
+            .collect();
+
+        for w in workers {
+            w.join().unwrap();
+        }
+
+        drop(stack);
+
+        assert!(drops.load(Ordering::SeqCst) == 8 * 2000);
+    }
+
+    #[test]
+    fn concurrent_stack_should_be_send_and_sync() {
+        fn assert_send_sync<S: Send + Sync>() {}
+
+        assert_send_sync::<ConcurrentStack<String>>();
+    }
+}
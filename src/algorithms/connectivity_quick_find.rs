@@ -1,5 +1,7 @@
-#[repr(transparent)]
-pub struct Network(Vec<usize>);
+pub struct Network {
+    ids: Vec<usize>,
+    count: usize,
+}
 
 impl Network {
     pub fn new(num_elts: usize) -> Network {
@@ -9,22 +11,47 @@ impl Network {
             ids.push(id);
         }
 
-        Network(ids)
+        Network {
+            ids,
+            count: num_elts,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ids.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.ids.len()
+    }
+
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    pub fn find(&self, p: usize) -> usize {
+        self.ids[p]
     }
 
     pub fn connected(&self, p: usize, q: usize) -> bool {
-        self.0[p] == self.0[q]
+        self.ids[p] == self.ids[q]
     }
 
     pub fn union(&mut self, p: usize, q: usize) {
-        let pid = self.0[p];
-        let qid = self.0[q];
+        let pid = self.ids[p];
+        let qid = self.ids[q];
 
-        for i in 0..self.0.len() {
-            if self.0[i] == pid {
-                self.0[i] = qid;
+        if pid == qid {
+            return;
+        }
+
+        for i in 0..self.ids.len() {
+            if self.ids[i] == pid {
+                self.ids[i] = qid;
             }
         }
+
+        self.count -= 1;
     }
 }
 
@@ -1,15 +1,36 @@
-#[repr(transparent)]
-pub struct Network(Vec<usize>);
+pub struct Network {
+    tree: Vec<usize>,
+    count: usize,
+}
 
 impl Network {
     pub fn new(num_elts: usize) -> Network {
-        let mut ids = Vec::with_capacity(num_elts);
+        let mut tree = Vec::with_capacity(num_elts);
 
         for id in 0..num_elts {
-            ids.push(id);
+            tree.push(id);
+        }
+
+        Network {
+            tree,
+            count: num_elts,
         }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.tree.is_empty()
+    }
 
-        Network(ids)
+    pub fn len(&self) -> usize {
+        self.tree.len()
+    }
+
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    pub fn find(&self, p: usize) -> usize {
+        self.root(p)
     }
 
     pub fn connected(&self, p: usize, q: usize) -> bool {
@@ -20,14 +41,19 @@ impl Network {
         let root_p = self.root(p);
         let root_q = self.root(q);
 
-        self.0[root_p] = root_q;
+        if root_p == root_q {
+            return;
+        }
+
+        self.tree[root_p] = root_q;
+        self.count -= 1;
     }
 
     fn root(&self, node: usize) -> usize {
         let mut current_node = node;
 
-        while current_node != self.0[current_node] {
-            current_node = self.0[current_node];
+        while current_node != self.tree[current_node] {
+            current_node = self.tree[current_node];
         }
 
         current_node
@@ -1,6 +1,11 @@
+use std::cell::Cell;
+
+// Weighted quick-union with path halving. Parent links live in cells so that
+// `find` and `connected` can compress paths through a shared reference.
 pub struct Network {
-    tree: Vec<usize>,
+    tree: Vec<Cell<usize>>,
     weights: Vec<usize>,
+    count: usize,
 }
 
 impl Network {
@@ -9,14 +14,34 @@ impl Network {
         let mut weights = Vec::with_capacity(num_elts);
 
         for id in 0..(num_elts) {
-            tree.push(id);
-            weights.push(0)
+            tree.push(Cell::new(id));
+            weights.push(1)
+        }
+
+        Network {
+            tree,
+            weights,
+            count: num_elts,
         }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.tree.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.tree.len()
+    }
 
-        Network { tree, weights }
+    pub fn count(&self) -> usize {
+        self.count
     }
 
-    pub fn connected(&mut self, p: usize, q: usize) -> bool {
+    pub fn find(&self, p: usize) -> usize {
+        self.root(p)
+    }
+
+    pub fn connected(&self, p: usize, q: usize) -> bool {
         self.root(p) == self.root(q)
     }
 
@@ -29,25 +54,41 @@ impl Network {
         }
 
         if self.weights[root_p] < self.weights[root_q] {
-            self.tree[root_p] = self.tree[root_q];
+            self.tree[root_p].set(root_q);
             self.weights[root_q] += self.weights[root_p];
         } else {
-            self.tree[root_q] = self.tree[root_p];
+            self.tree[root_q].set(root_p);
             self.weights[root_p] += self.weights[root_q];
         }
+
+        self.count -= 1;
+    }
+
+    fn root(&self, node: usize) -> usize {
+        halve_path(&self.tree, node, |_, _| {})
     }
+}
 
-    fn root(&mut self, node: usize) -> usize {
-        let mut current_node = node;
+// Walks from `node` up to its root, pointing every node on the way at its
+// grandparent. `skip(node, parent)` runs just before `node` is relinked past
+// `parent`, so union-find variants can update what they store per link.
+pub(crate) fn halve_path<F>(tree: &[Cell<usize>], node: usize, mut skip: F) -> usize
+where
+    F: FnMut(usize, usize),
+{
+    let mut current_node = node;
 
-        while current_node != self.tree[current_node] {
-            self.tree[current_node] = self.tree[self.tree[current_node]];
+    while current_node != tree[current_node].get() {
+        let parent = tree[current_node].get();
+        let grandparent = tree[parent].get();
 
-            current_node = self.tree[current_node];
-        }
+        skip(current_node, parent);
+        tree[current_node].set(grandparent);
 
-        current_node
+        current_node = grandparent;
     }
+
+    current_node
 }
 
 #[cfg(test)]
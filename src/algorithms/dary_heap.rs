@@ -0,0 +1,274 @@
+use std::cmp::Ordering;
+
+// Priority queue on a heap where every node has `arity` children, laid out
+// from position 0 so the children of k are arity * k + 1 ..= arity * k + arity.
+// As in PriorityQueue, the item that compares greatest under `comparator` is
+// deleted first.
+pub struct DaryHeap<T, F = fn(&T, &T) -> Ordering> {
+    queue: Vec<T>,
+    arity: usize,
+    comparator: F,
+}
+
+// Deletes items in priority order. Whatever is left when it is dropped is
+// discarded.
+pub struct Drain<'a, T, F>(&'a mut DaryHeap<T, F>);
+
+impl<T: Ord> DaryHeap<T> {
+    pub fn new(arity: usize, is_max: bool) -> Self {
+        DaryHeap::from_vec(Vec::new(), arity, is_max)
+    }
+
+    pub fn from_vec(items: Vec<T>, arity: usize, is_max: bool) -> Self {
+        if is_max {
+            DaryHeap::from_vec_by(items, arity, |a: &T, b: &T| a.cmp(b))
+        } else {
+            DaryHeap::from_vec_by(items, arity, |a: &T, b: &T| b.cmp(a))
+        }
+    }
+}
+
+impl<T> DaryHeap<T> {
+    pub fn new_by<F>(arity: usize, comparator: F) -> DaryHeap<T, F>
+    where
+        F: Fn(&T, &T) -> Ordering,
+    {
+        DaryHeap::from_vec_by(Vec::new(), arity, comparator)
+    }
+
+    pub fn new_by_key<K, G>(arity: usize, key: G) -> DaryHeap<T, impl Fn(&T, &T) -> Ordering>
+    where
+        K: Ord,
+        G: Fn(&T) -> K,
+    {
+        DaryHeap::new_by(arity, move |a: &T, b: &T| key(a).cmp(&key(b)))
+    }
+
+    // Bottom-up heap construction in O(n).
+    pub fn from_vec_by<F>(items: Vec<T>, arity: usize, comparator: F) -> DaryHeap<T, F>
+    where
+        F: Fn(&T, &T) -> Ordering,
+    {
+        assert!(arity >= 2, "arity must be at least 2");
+
+        let mut heap = DaryHeap {
+            queue: items,
+            arity,
+            comparator,
+        };
+
+        if heap.queue.len() > 1 {
+            for k in (0..=(heap.queue.len() - 2) / arity).rev() {
+                heap.sink(k, heap.queue.len());
+            }
+        }
+
+        heap
+    }
+}
+
+impl<T, F> DaryHeap<T, F>
+where
+    F: Fn(&T, &T) -> Ordering,
+{
+    pub fn arity(&self) -> usize {
+        self.arity
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.queue.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.queue.len()
+    }
+
+    pub fn peek(&self) -> Option<&T> {
+        self.queue.first()
+    }
+
+    pub fn insert(&mut self, key: T) {
+        self.queue.push(key);
+        self.swim(self.queue.len() - 1);
+    }
+
+    pub fn delete(&mut self) -> Option<T> {
+        if self.queue.is_empty() {
+            return None;
+        }
+
+        let top = self.queue.swap_remove(0);
+        self.sink(0, self.queue.len());
+
+        Some(top)
+    }
+
+    pub fn clear(&mut self) {
+        self.queue.clear();
+    }
+
+    pub fn drain(&mut self) -> Drain<'_, T, F> {
+        Drain(self)
+    }
+
+    // Ascending under the comparator, like PriorityQueue::into_sorted_vec.
+    pub fn into_sorted_vec(mut self) -> Vec<T> {
+        let mut end = self.queue.len();
+
+        while end > 1 {
+            end -= 1;
+            self.queue.swap(0, end);
+            self.sink(0, end);
+        }
+
+        self.queue
+    }
+
+    fn swim(&mut self, k: usize) {
+        let mut k = k;
+
+        while k > 0 {
+            let parent = (k - 1) / self.arity;
+
+            if !self.compare(parent, k) {
+                break;
+            }
+
+            self.queue.swap(k, parent);
+            k = parent;
+        }
+    }
+
+    // Sinks within the prefix of length `end`.
+    fn sink(&mut self, k: usize, end: usize) {
+        let mut k = k;
+
+        loop {
+            let first = self.arity * k + 1;
+
+            if first >= end {
+                break;
+            }
+
+            let mut j = first;
+
+            for child in first + 1..(first + self.arity).min(end) {
+                if self.compare(j, child) {
+                    j = child;
+                }
+            }
+
+            if !self.compare(k, j) {
+                break;
+            }
+
+            self.queue.swap(k, j);
+            k = j;
+        }
+    }
+
+    fn compare(&self, a: usize, b: usize) -> bool {
+        (self.comparator)(&self.queue[a], &self.queue[b]) == Ordering::Less
+    }
+}
+
+impl<'a, T, F> Iterator for Drain<'a, T, F>
+where
+    F: Fn(&T, &T) -> Ordering,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.delete()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.0.len(), Some(self.0.len()))
+    }
+}
+
+impl<'a, T, F> Drop for Drain<'a, T, F> {
+    fn drop(&mut self) {
+        self.0.queue.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::algorithms::dary_heap::DaryHeap;
+
+    #[test]
+    fn interface_operations_for_max_dary_heap_should_work_as_expected() {
+        for arity in 2..9 {
+            let mut heap = DaryHeap::<isize>::new(arity, true);
+
+            assert!(heap.is_empty());
+            assert!(heap.delete() == None);
+
+            for v in 0..100 {
+                heap.insert(v);
+            }
+
+            assert!(heap.len() == 100);
+            assert!(heap.peek() == Some(&99));
+
+            for v in 0..100 {
+                assert!(heap.delete() == Some(99 - v));
+            }
+
+            for v in [1, 10, 20, 5, -10, -30, -2, 1, 5].iter() {
+                heap.insert(*v);
+            }
+
+            let drained: Vec<_> = heap.drain().collect();
+
+            assert!(drained == vec![20, 10, 5, 5, 1, 1, -2, -10, -30]);
+            assert!(heap.is_empty());
+        }
+    }
+
+    #[test]
+    fn heapified_min_dary_heap_should_delete_in_ascending_order() {
+        let items: Vec<isize> = (0..300).map(|i| (i * 7919) % 307 - 150).collect();
+        let mut sorted = items.clone();
+        sorted.sort();
+
+        for arity in &[2, 3, 4, 8] {
+            let mut heap = DaryHeap::from_vec(items.clone(), *arity, false);
+
+            assert!(heap.arity() == *arity);
+            assert!(heap.peek() == Some(&sorted[0]));
+
+            for v in sorted.iter().take(150) {
+                assert!(heap.delete() == Some(*v));
+            }
+
+            heap.clear();
+
+            assert!(heap.delete() == None);
+
+            let heap = DaryHeap::from_vec(items.clone(), *arity, true);
+
+            assert!(heap.into_sorted_vec() == sorted);
+        }
+
+        let mut heap = DaryHeap::new_by_key(4, |word: &&str| word.len());
+
+        for word in "a quick brown fox jumped".split(' ') {
+            heap.insert(word);
+        }
+
+        assert!(heap.delete() == Some("jumped"));
+
+        let mut heap = DaryHeap::new_by(4, |a: &(u32, u32), b: &(u32, u32)| {
+            b.0.cmp(&a.0).then(a.1.cmp(&b.1))
+        });
+
+        heap.insert((3, 1));
+        heap.insert((1, 1));
+        heap.insert((3, 5));
+
+        assert!(heap.delete() == Some((1, 1)));
+        assert!(heap.delete() == Some((3, 5)));
+    }
+}
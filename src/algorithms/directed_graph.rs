@@ -1,9 +1,10 @@
+use crate::algorithms::adjacency::Adjacency;
 use crate::algorithms::linked_list_queue::LinkedQueue;
 use crate::algorithms::linked_list_stack::LinkedStack;
 
-struct DGraph {
+struct DGraph<A = Vec<LinkedStack<usize>>> {
     num_vertices: usize,
-    edges: Vec<LinkedStack<usize>>,
+    edges: A,
 }
 
 struct DFSPaths {
@@ -38,40 +39,40 @@ struct KosarajuSharirSCC {
 
 impl DGraph {
     pub fn new(num_vertices: usize) -> Self {
-        let mut edges = Vec::with_capacity(num_vertices);
+        DGraph::with_adjacency(num_vertices)
+    }
 
-        for v in 0..num_vertices {
-            edges.insert(v, LinkedStack::new());
-        }
+    pub fn get_adj_edges<'a>(&'a self, v: usize) -> &'a LinkedStack<usize> {
+        &self.edges[v]
+    }
 
+    pub fn get_adj_edges_mut<'a>(&'a mut self, v: usize) -> &'a mut LinkedStack<usize> {
+        &mut self.edges[v]
+    }
+}
+
+impl<A: Adjacency> DGraph<A> {
+    pub fn with_adjacency(num_vertices: usize) -> Self {
         DGraph {
             num_vertices,
-            edges,
+            edges: A::with_vertices(num_vertices),
         }
     }
 
     pub fn add_edge(&mut self, v: usize, w: usize) {
-        self.edges[v].push(w);
+        self.edges.push(v, w);
     }
 
-    pub fn get_adj_edges<'a>(&'a self, v: usize) -> &'a LinkedStack<usize> {
-        &self.edges[v]
+    pub fn adj(&self, v: usize) -> A::Iter<'_> {
+        self.edges.iter(v)
     }
 
-    pub fn get_adj_edges_mut<'a>(&'a mut self, v: usize) -> &'a mut LinkedStack<usize> {
-        &mut self.edges[v]
-    }
-
-    pub fn reverse(&self) -> DGraph {
-        let mut edges = Vec::with_capacity(self.num_vertices);
+    pub fn reverse(&self) -> DGraph<A> {
+        let mut edges = A::with_vertices(self.num_vertices);
 
         for v in 0..self.num_vertices {
-            edges.insert(v, LinkedStack::new());
-        }
-
-        for v in 0..self.num_vertices {
-            for w in self.edges[v].iter() {
-                edges[*w].push(v);
+            for w in self.edges.iter(v) {
+                edges.push(*w, v);
             }
         }
 
@@ -83,7 +84,7 @@ impl DGraph {
 }
 
 impl DFSPaths {
-    pub fn new(g: &DGraph, origin: usize) -> Self {
+    pub fn new<A: Adjacency>(g: &DGraph<A>, origin: usize) -> Self {
         let mut paths = DFSPaths {
             marked: vec![false; g.num_vertices],
             edge_to: vec![None; g.num_vertices],
@@ -117,10 +118,10 @@ impl DFSPaths {
         }
     }
 
-    fn dfs(&mut self, g: &DGraph, v: usize) {
+    fn dfs<A: Adjacency>(&mut self, g: &DGraph<A>, v: usize) {
         self.marked[v] = true;
 
-        for w in g.get_adj_edges(v).iter() {
+        for w in g.adj(v) {
             if !self.marked[*w] {
                 self.dfs(g, *w);
                 self.edge_to[*w] = Some(v);
@@ -130,7 +131,7 @@ impl DFSPaths {
 }
 
 impl BFSPaths {
-    pub fn new(g: &DGraph, origin: usize) -> Self {
+    pub fn new<A: Adjacency>(g: &DGraph<A>, origin: usize) -> Self {
         let mut paths = BFSPaths {
             marked: vec![false; g.num_vertices],
             edge_to: vec![None; g.num_vertices],
@@ -164,7 +165,7 @@ impl BFSPaths {
         }
     }
 
-    fn bfs(&mut self, g: &DGraph, v: usize) {
+    fn bfs<A: Adjacency>(&mut self, g: &DGraph<A>, v: usize) {
         let mut q = LinkedQueue::new();
 
         q.enqueue(self.origin);
@@ -173,7 +174,7 @@ impl BFSPaths {
         while !q.is_empty() {
             let v = q.dequeue().unwrap();
 
-            for w in g.get_adj_edges(v).iter() {
+            for w in g.adj(v) {
                 if !self.marked[*w] {
                     q.enqueue(*w);
                     self.marked[*w] = true;
@@ -185,7 +186,7 @@ impl BFSPaths {
 }
 
 impl TopologicalSort {
-    pub fn new(g: &DGraph) -> Self {
+    pub fn new<A: Adjacency>(g: &DGraph<A>) -> Self {
         let mut ts = TopologicalSort {
             marked: vec![false; g.num_vertices],
             reverse_post: LinkedStack::new(),
@@ -208,10 +209,10 @@ impl TopologicalSort {
         &mut self.reverse_post
     }
 
-    fn dfs(&mut self, g: &DGraph, v: usize) {
+    fn dfs<A: Adjacency>(&mut self, g: &DGraph<A>, v: usize) {
         self.marked[v] = true;
 
-        for w in g.get_adj_edges(v).iter() {
+        for w in g.adj(v) {
             if !self.marked[*w] {
                 self.dfs(g, *w);
             }
@@ -222,7 +223,7 @@ impl TopologicalSort {
 }
 
 impl DirectedCycle {
-    pub fn new(g: &DGraph) -> Self {
+    pub fn new<A: Adjacency>(g: &DGraph<A>) -> Self {
         let mut dc = DirectedCycle {
             marked: vec![false; g.num_vertices],
             on_stack: vec![false; g.num_vertices],
@@ -253,11 +254,11 @@ impl DirectedCycle {
         &mut self.cycle
     }
 
-    fn dfs(&mut self, g: &DGraph, v: usize) {
+    fn dfs<A: Adjacency>(&mut self, g: &DGraph<A>, v: usize) {
         self.on_stack[v] = true;
         self.marked[v] = true;
 
-        for w in g.get_adj_edges(v).iter() {
+        for w in g.adj(v) {
             if self.has_cycle() {
                 return;
             }
@@ -282,7 +283,7 @@ impl DirectedCycle {
 }
 
 impl KosarajuSharirSCC {
-    pub fn new(g: &DGraph) -> Self {
+    pub fn new<A: Adjacency>(g: &DGraph<A>) -> Self {
         let mut cc = KosarajuSharirSCC {
             marked: vec![false; g.num_vertices],
             id: vec![None; g.num_vertices],
@@ -309,11 +310,11 @@ impl KosarajuSharirSCC {
         self.id[v] == self.id[w]
     }
 
-    fn dfs(&mut self, g: &DGraph, v: usize) {
+    fn dfs<A: Adjacency>(&mut self, g: &DGraph<A>, v: usize) {
         self.marked[v] = true;
         self.id[v] = Some(self.count);
 
-        for w in g.get_adj_edges(v).iter() {
+        for w in g.adj(v) {
             if !self.marked[*w] {
                 self.dfs(g, *w);
             }
@@ -323,6 +324,7 @@ impl KosarajuSharirSCC {
 
 #[cfg(test)]
 mod tests {
+    use crate::algorithms::adjacency::ArenaAdjacency;
     use crate::algorithms::directed_graph::{
         BFSPaths, DFSPaths, DGraph, DirectedCycle, KosarajuSharirSCC, TopologicalSort,
     };
@@ -547,4 +549,37 @@ mod tests {
         assert!(cc.strongly_connected(9, 10));
         assert!(cc.strongly_connected(9, 12));
     }
+
+    #[test]
+    fn arena_adjacency_should_behave_like_linked_adjacency() {
+        let mut g = DGraph::new(7);
+        let mut ag = DGraph::<ArenaAdjacency>::with_adjacency(7);
+
+        for (v, w) in &[
+            (0, 5),
+            (0, 2),
+            (0, 1),
+            (3, 6),
+            (3, 5),
+            (3, 4),
+            (5, 2),
+            (6, 4),
+            (6, 0),
+            (3, 2),
+            (1, 4),
+        ] {
+            g.add_edge(*v, *w);
+            ag.add_edge(*v, *w);
+        }
+
+        let sorting = TopologicalSort::new(&g);
+        let arena_sorting = TopologicalSort::new(&ag);
+
+        assert!(sorting.reverse_post() == arena_sorting.reverse_post());
+
+        let reversed = ag.reverse();
+
+        assert!(reversed.adj(4).eq(g.reverse().adj(4)));
+        assert!(!DirectedCycle::new(&ag).has_cycle());
+    }
 }
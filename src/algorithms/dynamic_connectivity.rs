@@ -0,0 +1,250 @@
+use std::collections::HashMap;
+
+use crate::algorithms::rollback_union_find::RollbackUnionFind;
+
+// Offline dynamic connectivity. Edge insertions, deletions and queries are
+// recorded first; `solve` then places every edge on the nodes of a segment
+// tree over the queries that it spans, and walks the tree depth-first, adding
+// a node's edges on the way down and rolling them back on the way up. Each
+// edge lands on O(log q) nodes, so solving takes O((e log q + q) log n).
+pub struct DynamicConnectivity {
+    len: usize,
+    queries: Vec<(usize, usize)>,
+    open: HashMap<(usize, usize), Vec<usize>>,
+    lifetimes: Vec<(usize, usize, (usize, usize))>,
+}
+
+impl DynamicConnectivity {
+    pub fn new(num_elts: usize) -> Self {
+        DynamicConnectivity {
+            len: num_elts,
+            queries: Vec::new(),
+            open: HashMap::new(),
+            lifetimes: Vec::new(),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn num_queries(&self) -> usize {
+        self.queries.len()
+    }
+
+    // Parallel edges are allowed; each needs its own removal.
+    pub fn add_edge(&mut self, p: usize, q: usize) {
+        assert!(p < self.len && q < self.len, "vertex out of range");
+
+        let start = self.queries.len();
+
+        self.open.entry(edge(p, q)).or_default().push(start);
+    }
+
+    pub fn remove_edge(&mut self, p: usize, q: usize) {
+        let edge = edge(p, q);
+        let start = self
+            .open
+            .get_mut(&edge)
+            .and_then(Vec::pop)
+            .expect("edge is not present");
+
+        self.lifetimes.push((start, self.queries.len(), edge));
+    }
+
+    // Asks whether p and q are connected at this point of the timeline and
+    // returns the index of the answer in `solve`.
+    pub fn query(&mut self, p: usize, q: usize) -> usize {
+        assert!(p < self.len && q < self.len, "vertex out of range");
+
+        self.queries.push((p, q));
+        self.queries.len() - 1
+    }
+
+    pub fn solve(self) -> Vec<bool> {
+        let num_queries = self.queries.len();
+        let mut answers = vec![false; num_queries];
+
+        if num_queries == 0 {
+            return answers;
+        }
+
+        let mut tree = vec![Vec::new(); 4 * num_queries];
+        let lifetimes = self.open.into_iter().flat_map(|(edge, starts)| {
+            starts
+                .into_iter()
+                .map(move |start| (start, num_queries, edge))
+        });
+
+        for (start, end, edge) in self.lifetimes.into_iter().chain(lifetimes) {
+            if start < end {
+                cover(&mut tree, 1, 0, num_queries, start, end, edge);
+            }
+        }
+
+        let mut set = RollbackUnionFind::new(self.len);
+
+        answer(
+            &tree,
+            1,
+            0,
+            num_queries,
+            &self.queries,
+            &mut set,
+            &mut answers,
+        );
+
+        answers
+    }
+}
+
+fn edge(p: usize, q: usize) -> (usize, usize) {
+    (p.min(q), p.max(q))
+}
+
+// Adds the edge to the nodes whose query range [lo, hi) lies inside
+// [start, end).
+fn cover(
+    tree: &mut Vec<Vec<(usize, usize)>>,
+    node: usize,
+    lo: usize,
+    hi: usize,
+    start: usize,
+    end: usize,
+    edge: (usize, usize),
+) {
+    if end <= lo || hi <= start {
+        return;
+    }
+
+    if start <= lo && hi <= end {
+        tree[node].push(edge);
+        return;
+    }
+
+    let mid = (lo + hi) / 2;
+
+    cover(tree, 2 * node, lo, mid, start, end, edge);
+    cover(tree, 2 * node + 1, mid, hi, start, end, edge);
+}
+
+fn answer(
+    tree: &[Vec<(usize, usize)>],
+    node: usize,
+    lo: usize,
+    hi: usize,
+    queries: &[(usize, usize)],
+    set: &mut RollbackUnionFind,
+    answers: &mut Vec<bool>,
+) {
+    let snapshot = set.snapshot();
+
+    for &(p, q) in tree[node].iter() {
+        set.union(p, q);
+    }
+
+    if hi - lo == 1 {
+        let (p, q) = queries[lo];
+        answers[lo] = set.connected(p, q);
+    } else {
+        let mid = (lo + hi) / 2;
+
+        answer(tree, 2 * node, lo, mid, queries, set, answers);
+        answer(tree, 2 * node + 1, mid, hi, queries, set, answers);
+    }
+
+    set.rollback(snapshot);
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    use crate::algorithms::connectivity_quick_union_improved::Network;
+    use crate::algorithms::dynamic_connectivity::DynamicConnectivity;
+
+    #[test]
+    fn queries_should_see_the_edges_present_at_their_time() {
+        let mut timeline = DynamicConnectivity::new(5);
+
+        let before = timeline.query(0, 1);
+
+        timeline.add_edge(0, 1);
+        timeline.add_edge(1, 2);
+
+        let joined = timeline.query(0, 2);
+
+        timeline.add_edge(2, 1);
+        timeline.remove_edge(1, 2);
+
+        let parallel = timeline.query(2, 0);
+
+        timeline.remove_edge(1, 2);
+
+        let cut = timeline.query(0, 2);
+        let kept = timeline.query(1, 0);
+        let itself = timeline.query(4, 4);
+
+        timeline.add_edge(3, 4);
+
+        assert!(timeline.num_queries() == 6);
+        assert!(
+            timeline.solve() == vec![false, true, true, false, true, true],
+            "answers should follow insertion and deletion order"
+        );
+        assert!([before, joined, parallel, cut, kept, itself] == [0, 1, 2, 3, 4, 5]);
+        assert!(DynamicConnectivity::new(3).solve().is_empty());
+    }
+
+    #[test]
+    #[should_panic]
+    fn removing_a_missing_edge_should_panic() {
+        let mut timeline = DynamicConnectivity::new(3);
+
+        timeline.add_edge(0, 1);
+        timeline.remove_edge(1, 2);
+    }
+
+    #[test]
+    fn offline_answers_should_match_rebuilding_after_every_change() {
+        let n = 30;
+        let mut rng = StdRng::seed_from_u64(48);
+        let mut timeline = DynamicConnectivity::new(n);
+        let mut edges: Vec<(usize, usize)> = Vec::new();
+        let mut expected = Vec::new();
+
+        for _ in 0..1500 {
+            match rng.gen_range(0, 3) {
+                0 => {
+                    let edge = (rng.gen_range(0, n), rng.gen_range(0, n));
+
+                    timeline.add_edge(edge.0, edge.1);
+                    edges.push(edge);
+                }
+                1 if !edges.is_empty() => {
+                    let (p, q) = edges.swap_remove(rng.gen_range(0, edges.len()));
+
+                    timeline.remove_edge(q, p);
+                }
+                _ => {
+                    let (p, q) = (rng.gen_range(0, n), rng.gen_range(0, n));
+                    let mut network = Network::new(n);
+
+                    for &(a, b) in edges.iter() {
+                        network.union(a, b);
+                    }
+
+                    timeline.query(p, q);
+                    expected.push(network.connected(p, q));
+                }
+            }
+        }
+
+        assert!(timeline.solve() == expected);
+    }
+}
@@ -0,0 +1,494 @@
+use std::collections::HashMap;
+use std::fmt;
+
+use crate::algorithms::array_stack::ArrayStack;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Token {
+    Number(f64),
+    Identifier(String),
+    Operator(char),
+    LeftParen,
+    RightParen,
+    Comma,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Postfix {
+    Number(f64),
+    Variable(String),
+    Negate,
+    Binary(char),
+    Call(String, usize),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ExpressionError {
+    UnexpectedCharacter(char, usize),
+    InvalidNumber(String),
+    UnexpectedToken(Token),
+    MismatchedParentheses,
+    UnexpectedEnd,
+    UnknownVariable(String),
+    UnknownFunction(String),
+    UnknownOperator(char),
+    WrongArity {
+        name: String,
+        expected: usize,
+        found: usize,
+    },
+    DivisionByZero,
+}
+
+pub struct Evaluator {
+    variables: HashMap<String, f64>,
+    functions: HashMap<String, Function>,
+}
+
+type Body = Box<dyn Fn(&[f64]) -> f64>;
+
+struct Function {
+    arity: usize,
+    body: Body,
+}
+
+enum Pending {
+    Negate,
+    Binary(char),
+    LeftParen,
+    Call(String),
+}
+
+impl Evaluator {
+    pub fn new() -> Self {
+        Evaluator {
+            variables: HashMap::new(),
+            functions: HashMap::new(),
+        }
+    }
+
+    pub fn set_variable(&mut self, name: &str, value: f64) {
+        self.variables.insert(String::from(name), value);
+    }
+
+    pub fn register_function<F>(&mut self, name: &str, arity: usize, body: F)
+    where
+        F: Fn(&[f64]) -> f64 + 'static,
+    {
+        let function = Function {
+            arity,
+            body: Box::new(body),
+        };
+
+        self.functions.insert(String::from(name), function);
+    }
+
+    pub fn evaluate(&self, expression: &str) -> Result<f64, ExpressionError> {
+        let tokens = tokenize(expression)?;
+        let mut operands = ArrayStack::new();
+
+        shunting_yard(tokens, &mut |item| self.apply(&mut operands, item))?;
+
+        let result = operands.pop().ok_or(ExpressionError::UnexpectedEnd)?;
+
+        if operands.is_empty() {
+            Ok(result)
+        } else {
+            Err(ExpressionError::UnexpectedEnd)
+        }
+    }
+
+    pub fn evaluate_postfix(&self, postfix: &[Postfix]) -> Result<f64, ExpressionError> {
+        let mut operands = ArrayStack::new();
+
+        for item in postfix {
+            self.apply(&mut operands, item.clone())?;
+        }
+
+        let result = operands.pop().ok_or(ExpressionError::UnexpectedEnd)?;
+
+        if operands.is_empty() {
+            Ok(result)
+        } else {
+            Err(ExpressionError::UnexpectedEnd)
+        }
+    }
+
+    fn apply(&self, operands: &mut ArrayStack<f64>, item: Postfix) -> Result<(), ExpressionError> {
+        let value = match item {
+            Postfix::Number(n) => n,
+            Postfix::Variable(name) => match self.variables.get(&name) {
+                Some(value) => *value,
+                None => return Err(ExpressionError::UnknownVariable(name)),
+            },
+            Postfix::Negate => -operands.pop().ok_or(ExpressionError::UnexpectedEnd)?,
+            Postfix::Binary(op) => {
+                let b = operands.pop().ok_or(ExpressionError::UnexpectedEnd)?;
+                let a = operands.pop().ok_or(ExpressionError::UnexpectedEnd)?;
+
+                match op {
+                    '+' => a + b,
+                    '-' => a - b,
+                    '*' => a * b,
+                    '/' | '%' if b == 0.0 => return Err(ExpressionError::DivisionByZero),
+                    '/' => a / b,
+                    '%' => a % b,
+                    '^' => a.powf(b),
+                    _ => return Err(ExpressionError::UnknownOperator(op)),
+                }
+            }
+            Postfix::Call(name, argc) => {
+                let function = match self.functions.get(&name) {
+                    Some(function) => function,
+                    None => return Err(ExpressionError::UnknownFunction(name)),
+                };
+
+                if function.arity != argc {
+                    return Err(ExpressionError::WrongArity {
+                        name,
+                        expected: function.arity,
+                        found: argc,
+                    });
+                }
+
+                let mut args = vec![0.0; argc];
+                for i in (0..argc).rev() {
+                    args[i] = operands.pop().ok_or(ExpressionError::UnexpectedEnd)?;
+                }
+
+                (function.body)(&args)
+            }
+        };
+
+        operands.push(value);
+
+        Ok(())
+    }
+}
+
+impl Default for Evaluator {
+    fn default() -> Self {
+        Evaluator::new()
+    }
+}
+
+pub fn tokenize(expression: &str) -> Result<Vec<Token>, ExpressionError> {
+    let chars: Vec<char> = expression.chars().collect();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+
+    while i < chars.len() {
+        let c = chars[i];
+
+        if c.is_whitespace() {
+            i += 1;
+        } else if c.is_ascii_digit() || c == '.' {
+            let start = i;
+            while i < chars.len() && (chars[i].is_ascii_digit() || chars[i] == '.') {
+                i += 1;
+            }
+
+            let literal: String = chars[start..i].iter().collect();
+            match literal.parse() {
+                Ok(n) => tokens.push(Token::Number(n)),
+                Err(_) => return Err(ExpressionError::InvalidNumber(literal)),
+            }
+        } else if c.is_alphabetic() || c == '_' {
+            let start = i;
+            while i < chars.len() && (chars[i].is_alphanumeric() || chars[i] == '_') {
+                i += 1;
+            }
+
+            tokens.push(Token::Identifier(chars[start..i].iter().collect()));
+        } else {
+            let token = match c {
+                '+' | '-' | '*' | '/' | '%' | '^' => Token::Operator(c),
+                '(' => Token::LeftParen,
+                ')' => Token::RightParen,
+                ',' => Token::Comma,
+                _ => return Err(ExpressionError::UnexpectedCharacter(c, i)),
+            };
+
+            tokens.push(token);
+            i += 1;
+        }
+    }
+
+    Ok(tokens)
+}
+
+pub fn to_postfix(expression: &str) -> Result<Vec<Postfix>, ExpressionError> {
+    let tokens = tokenize(expression)?;
+    let mut output = Vec::new();
+
+    shunting_yard(tokens, &mut |item| {
+        output.push(item);
+        Ok(())
+    })?;
+
+    Ok(output)
+}
+
+pub fn to_rpn(expression: &str) -> Result<String, ExpressionError> {
+    let items: Vec<String> = to_postfix(expression)?
+        .iter()
+        .map(|item| item.to_string())
+        .collect();
+
+    Ok(items.join(" "))
+}
+
+// Dijkstra's shunting-yard algorithm. Operators wait on a stack until an
+// operator of lower precedence (or a closing parenthesis) releases them, and
+// every released item is handed to `emit` in postfix order.
+fn shunting_yard<E>(tokens: Vec<Token>, emit: &mut E) -> Result<(), ExpressionError>
+where
+    E: FnMut(Postfix) -> Result<(), ExpressionError>,
+{
+    let mut operators = ArrayStack::new();
+    let mut arg_counts = ArrayStack::new();
+    let mut expect_operand = true;
+    let mut tokens = tokens.into_iter().peekable();
+
+    while let Some(token) = tokens.next() {
+        match token {
+            Token::Number(n) if expect_operand => {
+                emit(Postfix::Number(n))?;
+                expect_operand = false;
+            }
+            Token::Identifier(name) if expect_operand => {
+                if tokens.peek() == Some(&Token::LeftParen) {
+                    tokens.next();
+                    operators.push(Pending::Call(name));
+
+                    if tokens.peek() == Some(&Token::RightParen) {
+                        arg_counts.push(0);
+                    } else {
+                        arg_counts.push(1);
+                    }
+                } else {
+                    emit(Postfix::Variable(name))?;
+                    expect_operand = false;
+                }
+            }
+            Token::Operator('-') if expect_operand => operators.push(Pending::Negate),
+            Token::Operator('+') if expect_operand => {}
+            Token::Operator(op) if !expect_operand => {
+                while let Some(top) = operators.peek() {
+                    let releases = match top {
+                        Pending::Negate => precedence('-', true) >= precedence(op, false),
+                        Pending::Binary(top_op) => {
+                            let (top_prec, prec) =
+                                (precedence(*top_op, false), precedence(op, false));
+                            top_prec > prec || (top_prec == prec && op != '^')
+                        }
+                        _ => false,
+                    };
+
+                    if !releases {
+                        break;
+                    }
+
+                    release(operators.pop().unwrap(), emit)?;
+                }
+
+                operators.push(Pending::Binary(op));
+                expect_operand = true;
+            }
+            Token::LeftParen if expect_operand => operators.push(Pending::LeftParen),
+            Token::Comma if !expect_operand => match release_until_paren(&mut operators, emit)? {
+                Pending::Call(name) => {
+                    operators.push(Pending::Call(name));
+                    *arg_counts.peek_mut().unwrap() += 1;
+                    expect_operand = true;
+                }
+                _ => return Err(ExpressionError::UnexpectedToken(Token::Comma)),
+            },
+            Token::RightParen if !expect_operand || is_empty_call(&operators, &arg_counts) => {
+                if let Pending::Call(name) = release_until_paren(&mut operators, emit)? {
+                    emit(Postfix::Call(name, arg_counts.pop().unwrap()))?;
+                }
+
+                expect_operand = false;
+            }
+            token => return Err(ExpressionError::UnexpectedToken(token)),
+        }
+    }
+
+    if expect_operand {
+        return Err(ExpressionError::UnexpectedEnd);
+    }
+
+    while let Some(pending) = operators.pop() {
+        match pending {
+            Pending::LeftParen | Pending::Call(_) => {
+                return Err(ExpressionError::MismatchedParentheses)
+            }
+            pending => release(pending, emit)?,
+        }
+    }
+
+    Ok(())
+}
+
+fn release<E>(pending: Pending, emit: &mut E) -> Result<(), ExpressionError>
+where
+    E: FnMut(Postfix) -> Result<(), ExpressionError>,
+{
+    match pending {
+        Pending::Negate => emit(Postfix::Negate),
+        Pending::Binary(op) => emit(Postfix::Binary(op)),
+        _ => Err(ExpressionError::MismatchedParentheses),
+    }
+}
+
+// Releases every operator up to the innermost open parenthesis, which is
+// popped and returned.
+fn release_until_paren<E>(
+    operators: &mut ArrayStack<Pending>,
+    emit: &mut E,
+) -> Result<Pending, ExpressionError>
+where
+    E: FnMut(Postfix) -> Result<(), ExpressionError>,
+{
+    loop {
+        match operators.pop() {
+            None => return Err(ExpressionError::MismatchedParentheses),
+            Some(Pending::Negate) => emit(Postfix::Negate)?,
+            Some(Pending::Binary(op)) => emit(Postfix::Binary(op))?,
+            Some(paren) => return Ok(paren),
+        }
+    }
+}
+
+fn is_empty_call(operators: &ArrayStack<Pending>, arg_counts: &ArrayStack<usize>) -> bool {
+    matches!(
+        (operators.peek(), arg_counts.peek()),
+        (Some(Pending::Call(_)), Some(0))
+    )
+}
+
+fn precedence(op: char, unary: bool) -> u8 {
+    match op {
+        '-' if unary => 3,
+        '+' | '-' => 1,
+        '*' | '/' | '%' => 2,
+        _ => 4,
+    }
+}
+
+impl fmt::Display for Postfix {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Postfix::Number(n) => write!(f, "{}", n),
+            Postfix::Variable(name) => write!(f, "{}", name),
+            Postfix::Negate => write!(f, "neg"),
+            Postfix::Binary(op) => write!(f, "{}", op),
+            Postfix::Call(name, argc) => write!(f, "{}/{}", name, argc),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::algorithms::expression::{to_postfix, to_rpn, Evaluator, ExpressionError, Postfix};
+
+    #[test]
+    fn arithmetic_expressions_should_be_evaluated_with_precedence() {
+        let e = Evaluator::new();
+
+        assert!(e.evaluate("1 + 2 * 3") == Ok(7.0));
+        assert!(e.evaluate("(1 + 2) * 3") == Ok(9.0));
+        assert!(e.evaluate("( 1 + ( ( 2 + 3 ) * ( 4 * 5 ) ) )") == Ok(101.0));
+        assert!(e.evaluate("10 - 4 - 3") == Ok(3.0));
+        assert!(e.evaluate("2 ^ 3 ^ 2") == Ok(512.0));
+        assert!(e.evaluate("7 % 4 + 8 / 2") == Ok(7.0));
+        assert!(e.evaluate("-2 ^ 2") == Ok(-4.0));
+        assert!(e.evaluate("2 * -3") == Ok(-6.0));
+        assert!(e.evaluate("--3") == Ok(3.0));
+        assert!(e.evaluate("-(1 + 2) * +4") == Ok(-12.0));
+        assert!(e.evaluate("0.5 * 4") == Ok(2.0));
+    }
+
+    #[test]
+    fn variables_and_functions_should_be_resolved() {
+        let mut e = Evaluator::new();
+
+        e.set_variable("rate", 0.25);
+        e.set_variable("base_cost", 80.0);
+        e.register_function("max", 2, |args| args[0].max(args[1]));
+        e.register_function("sqrt", 1, |args| args[0].sqrt());
+        e.register_function("pi", 0, |_| 3.0);
+
+        assert!(e.evaluate("base_cost * (1 + rate)") == Ok(100.0));
+        assert!(e.evaluate("max(rate, 2) + sqrt(16)") == Ok(6.0));
+        assert!(e.evaluate("max(1, max(2 * 3, 4)) - pi()") == Ok(3.0));
+        assert!(e.evaluate("-sqrt(base_cost + 1)") == Ok(-9.0));
+
+        assert!(
+            e.evaluate("unknown + 1")
+                == Err(ExpressionError::UnknownVariable(String::from("unknown")))
+        );
+        assert!(e.evaluate("foo(1)") == Err(ExpressionError::UnknownFunction(String::from("foo"))));
+        assert!(
+            e.evaluate("max(1)")
+                == Err(ExpressionError::WrongArity {
+                    name: String::from("max"),
+                    expected: 2,
+                    found: 1,
+                })
+        );
+    }
+
+    #[test]
+    fn malformed_expressions_should_be_rejected() {
+        let e = Evaluator::new();
+
+        assert!(e.evaluate("") == Err(ExpressionError::UnexpectedEnd));
+        assert!(e.evaluate("1 +") == Err(ExpressionError::UnexpectedEnd));
+        assert!(e.evaluate("(1 + 2") == Err(ExpressionError::MismatchedParentheses));
+        assert!(e.evaluate("1 + 2)") == Err(ExpressionError::MismatchedParentheses));
+        assert!(e.evaluate("1 / 0") == Err(ExpressionError::DivisionByZero));
+        assert!(e.evaluate("1 $ 2") == Err(ExpressionError::UnexpectedCharacter('$', 2)));
+        assert!(e.evaluate("1.2.3") == Err(ExpressionError::InvalidNumber(String::from("1.2.3"))));
+        assert!(e.evaluate("1 2").is_err());
+        assert!(e.evaluate("* 2").is_err());
+        assert!(e.evaluate("()").is_err());
+        assert!(e.evaluate("(1, 2)").is_err());
+    }
+
+    #[test]
+    fn expressions_should_be_converted_to_postfix() {
+        assert!(
+            to_rpn("3 + 4 * 2 / (1 - 5) ^ 2 ^ 3") == Ok(String::from("3 4 2 * 1 5 - 2 3 ^ ^ / +"))
+        );
+        assert!(to_rpn("-a + f(b, 2 * c)") == Ok(String::from("a neg b 2 c * f/2 +")));
+
+        let postfix = to_postfix("x * (y + 1)").unwrap();
+
+        assert!(
+            postfix
+                == vec![
+                    Postfix::Variable(String::from("x")),
+                    Postfix::Variable(String::from("y")),
+                    Postfix::Number(1.0),
+                    Postfix::Binary('+'),
+                    Postfix::Binary('*'),
+                ]
+        );
+
+        let mut e = Evaluator::new();
+        e.set_variable("x", 3.0);
+        e.set_variable("y", 4.0);
+
+        assert!(e.evaluate_postfix(&postfix) == Ok(15.0));
+
+        let unknown = [
+            Postfix::Number(1.0),
+            Postfix::Number(2.0),
+            Postfix::Binary('&'),
+        ];
+
+        assert!(e.evaluate_postfix(&unknown) == Err(ExpressionError::UnknownOperator('&')));
+    }
+}
@@ -0,0 +1,317 @@
+use std::cell::{Ref, RefCell};
+use std::mem;
+use std::rc::{Rc, Weak};
+
+use crate::algorithms::heaps::{HeapError, Owner};
+
+type Link<T> = Rc<RefCell<Node<T>>>;
+
+// Decreasing a key swaps items up the tree, so handles point at an anchor that
+// travels with the item and always refers to the node currently holding it.
+type Anchor<T> = RefCell<Weak<RefCell<Node<T>>>>;
+
+// `trees[k]` holds the binomial tree of degree k, if any, so melding two
+// heaps works like adding two binary numbers.
+pub struct BinomialHeap<T> {
+    trees: Vec<Option<Link<T>>>,
+    len: usize,
+    owner: Owner,
+}
+
+struct Node<T> {
+    item: T,
+    anchor: Rc<Anchor<T>>,
+    parent: Weak<RefCell<Node<T>>>,
+    children: Vec<Link<T>>,
+}
+
+pub struct Handle<T>(Weak<Anchor<T>>, Owner);
+
+impl<T> Clone for Handle<T> {
+    fn clone(&self) -> Self {
+        Handle(self.0.clone(), self.1.clone())
+    }
+}
+
+impl<T: Ord> BinomialHeap<T> {
+    pub fn new() -> Self {
+        BinomialHeap {
+            trees: Vec::new(),
+            len: 0,
+            owner: Owner::new(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn peek(&self) -> Option<Ref<'_, T>> {
+        self.min_degree().map(|degree| {
+            let root = self.trees[degree].as_ref().unwrap();
+
+            Ref::map(root.borrow(), |node| &node.item)
+        })
+    }
+
+    pub fn insert(&mut self, item: T) -> Handle<T> {
+        let node = Rc::new(RefCell::new(Node {
+            item,
+            anchor: Rc::new(RefCell::new(Weak::new())),
+            parent: Weak::new(),
+            children: Vec::new(),
+        }));
+
+        let anchor = node.borrow().anchor.clone();
+        *anchor.borrow_mut() = Rc::downgrade(&node);
+
+        self.merge(vec![Some(node)]);
+        self.len += 1;
+
+        Handle(Rc::downgrade(&anchor), self.owner.clone())
+    }
+
+    pub fn meld(&mut self, mut other: Self) {
+        self.merge(mem::take(&mut other.trees));
+        self.len += other.len;
+        self.owner.absorb(&other.owner);
+    }
+
+    pub fn delete_min(&mut self) -> Option<T> {
+        let degree = self.min_degree()?;
+        let root = self.trees[degree].take().unwrap();
+
+        while let Some(None) = self.trees.last() {
+            self.trees.pop();
+        }
+
+        let node = Rc::try_unwrap(root).ok().unwrap().into_inner();
+
+        for child in node.children.iter() {
+            child.borrow_mut().parent = Weak::new();
+        }
+
+        self.merge(node.children.into_iter().map(Some).collect());
+        self.len -= 1;
+
+        Some(node.item)
+    }
+
+    pub fn decrease_key(&mut self, handle: &Handle<T>, key: T) -> Result<(), HeapError> {
+        if !self.owner.owns(&handle.1) {
+            return Err(HeapError::StaleHandle);
+        }
+
+        let anchor = handle.0.upgrade().ok_or(HeapError::StaleHandle)?;
+        let mut node = anchor.borrow().upgrade().unwrap();
+
+        if key > node.borrow().item {
+            return Err(HeapError::KeyIncreased);
+        }
+
+        node.borrow_mut().item = key;
+
+        loop {
+            let parent = match node.borrow().parent.upgrade() {
+                None => break,
+                Some(parent) => parent,
+            };
+
+            if node.borrow().item >= parent.borrow().item {
+                break;
+            }
+
+            {
+                let mut child = node.borrow_mut();
+                let mut above = parent.borrow_mut();
+
+                mem::swap(&mut child.item, &mut above.item);
+                mem::swap(&mut child.anchor, &mut above.anchor);
+
+                *child.anchor.borrow_mut() = Rc::downgrade(&node);
+                *above.anchor.borrow_mut() = Rc::downgrade(&parent);
+            }
+
+            node = parent;
+        }
+
+        Ok(())
+    }
+
+    fn min_degree(&self) -> Option<usize> {
+        let mut min: Option<usize> = None;
+
+        for (degree, root) in self.trees.iter().enumerate() {
+            if let Some(root) = root {
+                let smaller = match min {
+                    None => true,
+                    Some(m) => root.borrow().item < self.trees[m].as_ref().unwrap().borrow().item,
+                };
+
+                if smaller {
+                    min = Some(degree);
+                }
+            }
+        }
+
+        min
+    }
+
+    fn merge(&mut self, other: Vec<Option<Link<T>>>) {
+        let mut other = other.into_iter();
+        let mut carry = None;
+        let mut degree = 0;
+
+        while degree < self.trees.len() || other.len() > 0 || carry.is_some() {
+            if degree == self.trees.len() {
+                self.trees.push(None);
+            }
+
+            let mut slots = [
+                self.trees[degree].take(),
+                other.next().flatten(),
+                carry.take(),
+            ];
+            let mut present = slots.iter_mut().filter_map(Option::take);
+
+            match (present.next(), present.next()) {
+                (Some(a), Some(b)) => {
+                    carry = Some(link(a, b));
+                    self.trees[degree] = present.next();
+                }
+                (a, _) => self.trees[degree] = a,
+            }
+
+            degree += 1;
+        }
+
+        while let Some(None) = self.trees.last() {
+            self.trees.pop();
+        }
+    }
+}
+
+impl<T: Ord> Default for BinomialHeap<T> {
+    fn default() -> Self {
+        BinomialHeap::new()
+    }
+}
+
+fn link<T: Ord>(a: Link<T>, b: Link<T>) -> Link<T> {
+    let (parent, child) = if b.borrow().item < a.borrow().item {
+        (b, a)
+    } else {
+        (a, b)
+    };
+
+    child.borrow_mut().parent = Rc::downgrade(&parent);
+    parent.borrow_mut().children.push(child);
+
+    parent
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::algorithms::heaps::binomial::BinomialHeap;
+    use crate::algorithms::heaps::HeapError;
+
+    #[test]
+    fn interface_operations_should_work_as_expected() {
+        let mut heap = BinomialHeap::new();
+
+        assert!(heap.is_empty());
+        assert!(heap.delete_min() == None);
+        assert!(heap.peek().is_none());
+
+        for v in [5, 3, 8, 1, 9, 2, 7].iter() {
+            heap.insert(*v);
+        }
+
+        assert!(heap.len() == 7);
+        assert!(heap.trees.len() == 3);
+        assert!(*heap.peek().unwrap() == 1);
+
+        let mut other = BinomialHeap::new();
+        other.insert(0);
+        other.insert(6);
+        heap.meld(other);
+
+        assert!(heap.len() == 9);
+        assert!(heap.trees.len() == 4);
+
+        for v in [0, 1, 2, 3, 5, 6, 7, 8, 9].iter() {
+            assert!(heap.delete_min() == Some(*v));
+        }
+
+        assert!(heap.is_empty());
+        assert!(heap.trees.is_empty());
+    }
+
+    #[test]
+    fn decrease_key_should_reorder_items_through_handles() {
+        let mut heap = BinomialHeap::new();
+        let handles: Vec<_> = (0..1000).map(|v| heap.insert(1000 + v)).collect();
+
+        assert!(heap.delete_min() == Some(1000));
+        assert!(heap.decrease_key(&handles[0], 0) == Err(HeapError::StaleHandle));
+        assert!(heap.decrease_key(&handles[1], 5000) == Err(HeapError::KeyIncreased));
+
+        for (i, handle) in handles.iter().enumerate().skip(1) {
+            if i % 2 == 0 {
+                heap.decrease_key(handle, i as i32 - 1000).unwrap();
+            }
+        }
+
+        // Handles follow their items as they move up the trees.
+        heap.decrease_key(&handles[998], -2000).unwrap();
+        heap.decrease_key(&handles[998], -3000).unwrap();
+
+        assert!(*heap.peek().unwrap() == -3000);
+
+        let mut previous = i32::min_value();
+
+        for _ in 0..999 {
+            let v = heap.delete_min().unwrap();
+
+            assert!(v >= previous);
+
+            previous = v;
+        }
+
+        assert!(heap.is_empty());
+    }
+
+    #[test]
+    fn handles_should_only_work_on_the_heap_that_holds_their_item() {
+        let mut first = BinomialHeap::new();
+        let mut second = BinomialHeap::new();
+        let mut third = BinomialHeap::new();
+
+        let a = first.insert(10);
+        let b = second.insert(20);
+        let c = third.insert(30);
+
+        assert!(first.decrease_key(&b, 1) == Err(HeapError::StaleHandle));
+        assert!(second.decrease_key(&a, 1) == Err(HeapError::StaleHandle));
+        assert!(*second.peek().unwrap() == 20);
+
+        second.meld(third);
+        first.meld(second);
+
+        assert!(first.decrease_key(&c, 3) == Ok(()));
+        assert!(first.decrease_key(&b, 2) == Ok(()));
+        assert!(first.decrease_key(&a, 1) == Ok(()));
+
+        let mut other = BinomialHeap::new();
+        other.insert(0);
+
+        assert!(other.decrease_key(&a, -1) == Err(HeapError::StaleHandle));
+        assert!(first.delete_min() == Some(1));
+        assert!(first.delete_min() == Some(2));
+        assert!(first.delete_min() == Some(3));
+    }
+}
@@ -0,0 +1,287 @@
+use std::cell::Ref;
+use std::mem;
+use std::rc::Rc;
+
+pub use crate::algorithms::heaps::tree::Handle;
+use crate::algorithms::heaps::tree::{self, Link};
+use crate::algorithms::heaps::{HeapError, Owner};
+use crate::algorithms::linked_list_stack::LinkedStack;
+
+// Roots are kept in a LinkedStack so melding is an O(1) append. Trees are only
+// consolidated by degree when the minimum is deleted.
+pub struct FibonacciHeap<T> {
+    roots: LinkedStack<Link<T>>,
+    min: Option<Link<T>>,
+    len: usize,
+    owner: Owner,
+}
+
+impl<T: Ord> FibonacciHeap<T> {
+    pub fn new() -> Self {
+        FibonacciHeap {
+            roots: LinkedStack::new(),
+            min: None,
+            len: 0,
+            owner: Owner::new(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.min.is_none()
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn peek(&self) -> Option<Ref<'_, T>> {
+        self.min
+            .as_ref()
+            .map(|min| Ref::map(min.borrow(), |node| &node.item))
+    }
+
+    pub fn insert(&mut self, item: T) -> Handle<T> {
+        let node = tree::node(item);
+        let handle = Handle(Rc::downgrade(&node), self.owner.clone());
+
+        self.push_root(node);
+        self.len += 1;
+
+        handle
+    }
+
+    pub fn meld(&mut self, mut other: Self) {
+        self.roots.append(&mut other.roots);
+
+        if let Some(min) = other.min.take() {
+            self.update_min(&min);
+        }
+
+        self.len += other.len;
+        self.owner.absorb(&other.owner);
+    }
+
+    pub fn delete_min(&mut self) -> Option<T> {
+        let min = self.min.take()?;
+        let mut trees = tree::take_children(&min);
+
+        while let Some(root) = self.roots.pop() {
+            if !Rc::ptr_eq(&root, &min) {
+                trees.push(root);
+            }
+        }
+
+        self.consolidate(trees);
+        self.len -= 1;
+
+        Some(tree::into_item(min))
+    }
+
+    pub fn decrease_key(&mut self, handle: &Handle<T>, key: T) -> Result<(), HeapError> {
+        if !self.owner.owns(&handle.1) {
+            return Err(HeapError::StaleHandle);
+        }
+
+        let node = handle.0.upgrade().ok_or(HeapError::StaleHandle)?;
+
+        if key > node.borrow().item {
+            return Err(HeapError::KeyIncreased);
+        }
+
+        node.borrow_mut().item = key;
+
+        let parent = node.borrow().parent.upgrade();
+
+        if let Some(parent) = parent {
+            if node.borrow().item < parent.borrow().item {
+                tree::cut(&parent, &node);
+                self.roots.push(node.clone());
+                self.cascading_cut(parent);
+            }
+        }
+
+        self.update_min(&node);
+
+        Ok(())
+    }
+
+    // A node that loses a second child is cut as well, which keeps the size
+    // of a tree exponential in its degree.
+    fn cascading_cut(&mut self, node: Link<T>) {
+        let mut node = node;
+
+        loop {
+            let parent = match node.borrow().parent.upgrade() {
+                None => break,
+                Some(parent) => parent,
+            };
+
+            if !node.borrow().marked {
+                node.borrow_mut().marked = true;
+                break;
+            }
+
+            tree::cut(&parent, &node);
+            self.roots.push(node);
+            node = parent;
+        }
+    }
+
+    fn consolidate(&mut self, trees: Vec<Link<T>>) {
+        let mut by_degree: Vec<Option<Link<T>>> = Vec::new();
+
+        for root in trees {
+            let mut root = root;
+
+            loop {
+                let degree = root.borrow().children.len();
+
+                if by_degree.len() <= degree {
+                    by_degree.resize_with(degree + 1, || None);
+                }
+
+                match by_degree[degree].take() {
+                    None => {
+                        by_degree[degree] = Some(root);
+                        break;
+                    }
+                    Some(other) => root = tree::link(root, other),
+                }
+            }
+        }
+
+        for root in by_degree.into_iter().flatten() {
+            self.push_root(root);
+        }
+    }
+
+    fn push_root(&mut self, node: Link<T>) {
+        self.update_min(&node);
+        self.roots.push(node);
+    }
+
+    fn update_min(&mut self, node: &Link<T>) {
+        let smaller = match &self.min {
+            None => true,
+            Some(min) => node.borrow().item < min.borrow().item,
+        };
+
+        if smaller {
+            self.min = Some(node.clone());
+        }
+    }
+}
+
+impl<T: Ord> Default for FibonacciHeap<T> {
+    fn default() -> Self {
+        FibonacciHeap::new()
+    }
+}
+
+impl<T> Drop for FibonacciHeap<T> {
+    fn drop(&mut self) {
+        self.min = None;
+
+        for root in mem::take(&mut self.roots) {
+            tree::release(root);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::algorithms::heaps::fibonacci::FibonacciHeap;
+    use crate::algorithms::heaps::HeapError;
+
+    #[test]
+    fn interface_operations_should_work_as_expected() {
+        let mut heap = FibonacciHeap::new();
+
+        assert!(heap.is_empty());
+        assert!(heap.delete_min() == None);
+        assert!(heap.peek().is_none());
+
+        for v in [5, 3, 8, 1, 9, 2, 7].iter() {
+            heap.insert(*v);
+        }
+
+        assert!(heap.len() == 7);
+        assert!(*heap.peek().unwrap() == 1);
+
+        let mut other = FibonacciHeap::new();
+        other.insert(0);
+        other.insert(6);
+        heap.meld(other);
+
+        assert!(heap.len() == 9);
+        assert!(*heap.peek().unwrap() == 0);
+
+        for v in [0, 1, 2, 3, 5, 6, 7, 8, 9].iter() {
+            assert!(heap.delete_min() == Some(*v));
+        }
+
+        assert!(heap.is_empty());
+    }
+
+    #[test]
+    fn decrease_key_should_reorder_items_through_handles() {
+        let mut heap = FibonacciHeap::new();
+        let handles: Vec<_> = (0..1000).map(|v| heap.insert(1000 + v)).collect();
+
+        assert!(heap.delete_min() == Some(1000));
+        assert!(heap.decrease_key(&handles[0], 0) == Err(HeapError::StaleHandle));
+        assert!(heap.decrease_key(&handles[1], 5000) == Err(HeapError::KeyIncreased));
+
+        // Decreasing many keys below their parents triggers cascading cuts.
+        for (i, handle) in handles.iter().enumerate().skip(1).rev() {
+            if i % 2 == 0 {
+                heap.decrease_key(handle, i as i32 - 1000).unwrap();
+            }
+        }
+
+        assert!(*heap.peek().unwrap() == -998);
+
+        let mut previous = i32::min_value();
+
+        for _ in 0..999 {
+            let v = heap.delete_min().unwrap();
+
+            assert!(v >= previous);
+
+            previous = v;
+        }
+
+        assert!(heap.is_empty());
+        assert!(heap.len() == 0);
+    }
+
+    #[test]
+    fn handles_should_only_work_on_the_heap_that_holds_their_item() {
+        let mut first = FibonacciHeap::new();
+        let mut second = FibonacciHeap::new();
+        let mut third = FibonacciHeap::new();
+
+        let a = first.insert(10);
+        let b = second.insert(20);
+        let c = third.insert(30);
+
+        assert!(first.decrease_key(&b, 1) == Err(HeapError::StaleHandle));
+        assert!(second.decrease_key(&a, 1) == Err(HeapError::StaleHandle));
+        assert!(*second.peek().unwrap() == 20);
+
+        second.meld(third);
+        first.meld(second);
+
+        assert!(first.decrease_key(&c, 3) == Ok(()));
+        assert!(first.decrease_key(&b, 2) == Ok(()));
+        assert!(first.decrease_key(&a, 1) == Ok(()));
+
+        let mut other = FibonacciHeap::new();
+        other.insert(0);
+
+        assert!(other.decrease_key(&a, -1) == Err(HeapError::StaleHandle));
+        assert!(first.delete_min() == Some(1));
+        assert!(first.delete_min() == Some(2));
+        assert!(first.delete_min() == Some(3));
+    }
+}
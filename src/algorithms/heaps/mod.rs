@@ -0,0 +1,258 @@
+use std::cell::RefCell;
+use std::cmp::Ordering;
+use std::ops::Deref;
+use std::rc::Rc;
+
+use crate::algorithms::priority_queue::PriorityQueue;
+
+pub mod binomial;
+pub mod fibonacci;
+pub mod pairing;
+mod tree;
+
+// Operations shared by the meldable heaps and PriorityQueue. Every
+// implementation states which item it keeps on top: the heaps in this module
+// keep their smallest item there, PriorityQueue whatever its comparator
+// orders first.
+pub trait Heap<T> {
+    type Handle;
+
+    type Peek<'a>: Deref<Target = T>
+    where
+        Self: 'a,
+        T: 'a;
+
+    fn len(&self) -> usize;
+
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn insert(&mut self, item: T) -> Self::Handle;
+
+    // The top item, which is the one `delete` removes next.
+    fn peek(&self) -> Option<Self::Peek<'_>>;
+
+    fn delete(&mut self) -> Option<T>;
+
+    fn meld(&mut self, other: Self)
+    where
+        Self: Sized;
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum HeapError {
+    StaleHandle,
+    KeyIncreased,
+}
+
+// Identity of a heap that its handles carry, so that a handle passed to the
+// wrong heap is rejected. Melding forwards the identity of the consumed heap
+// to the surviving one, which keeps the handles of both valid.
+#[derive(Clone)]
+pub(crate) struct Owner(Rc<RefCell<Option<Owner>>>);
+
+impl Owner {
+    pub(crate) fn new() -> Self {
+        Owner(Rc::new(RefCell::new(None)))
+    }
+
+    pub(crate) fn owns(&self, other: &Owner) -> bool {
+        Rc::ptr_eq(&self.0, &other.current().0)
+    }
+
+    pub(crate) fn absorb(&self, other: &Owner) {
+        *other.0.borrow_mut() = Some(self.clone());
+    }
+
+    // Follows the forwarding chain and points every identity on it straight
+    // at the end.
+    fn current(&self) -> Owner {
+        let mut chain = Vec::new();
+        let mut current = self.clone();
+
+        loop {
+            let next = current.0.borrow().clone();
+
+            match next {
+                None => break,
+                Some(next) => {
+                    chain.push(current);
+                    current = next;
+                }
+            }
+        }
+
+        for owner in chain {
+            *owner.0.borrow_mut() = Some(current.clone());
+        }
+
+        current
+    }
+}
+
+// Deletes the item its comparator orders first.
+impl<T, F> Heap<T> for PriorityQueue<T, F>
+where
+    F: Fn(&T, &T) -> Ordering,
+{
+    type Handle = ();
+    type Peek<'a>
+        = &'a T
+    where
+        Self: 'a,
+        T: 'a;
+
+    fn len(&self) -> usize {
+        PriorityQueue::len(self)
+    }
+
+    fn insert(&mut self, item: T) {
+        PriorityQueue::insert(self, item)
+    }
+
+    fn peek(&self) -> Option<&T> {
+        PriorityQueue::peek(self)
+    }
+
+    fn delete(&mut self) -> Option<T> {
+        PriorityQueue::delete(self)
+    }
+
+    fn meld(&mut self, other: Self) {
+        PriorityQueue::meld(self, other)
+    }
+}
+
+// Deletes the smallest item.
+impl<T: Ord> Heap<T> for binomial::BinomialHeap<T> {
+    type Handle = binomial::Handle<T>;
+    type Peek<'a>
+        = std::cell::Ref<'a, T>
+    where
+        T: 'a;
+
+    fn len(&self) -> usize {
+        binomial::BinomialHeap::len(self)
+    }
+
+    fn insert(&mut self, item: T) -> Self::Handle {
+        binomial::BinomialHeap::insert(self, item)
+    }
+
+    fn peek(&self) -> Option<Self::Peek<'_>> {
+        binomial::BinomialHeap::peek(self)
+    }
+
+    fn delete(&mut self) -> Option<T> {
+        self.delete_min()
+    }
+
+    fn meld(&mut self, other: Self) {
+        binomial::BinomialHeap::meld(self, other)
+    }
+}
+
+// Deletes the smallest item.
+impl<T: Ord> Heap<T> for pairing::PairingHeap<T> {
+    type Handle = pairing::Handle<T>;
+    type Peek<'a>
+        = std::cell::Ref<'a, T>
+    where
+        T: 'a;
+
+    fn len(&self) -> usize {
+        pairing::PairingHeap::len(self)
+    }
+
+    fn insert(&mut self, item: T) -> Self::Handle {
+        pairing::PairingHeap::insert(self, item)
+    }
+
+    fn peek(&self) -> Option<Self::Peek<'_>> {
+        pairing::PairingHeap::peek(self)
+    }
+
+    fn delete(&mut self) -> Option<T> {
+        self.delete_min()
+    }
+
+    fn meld(&mut self, other: Self) {
+        pairing::PairingHeap::meld(self, other)
+    }
+}
+
+// Deletes the smallest item.
+impl<T: Ord> Heap<T> for fibonacci::FibonacciHeap<T> {
+    type Handle = fibonacci::Handle<T>;
+    type Peek<'a>
+        = std::cell::Ref<'a, T>
+    where
+        T: 'a;
+
+    fn len(&self) -> usize {
+        fibonacci::FibonacciHeap::len(self)
+    }
+
+    fn insert(&mut self, item: T) -> Self::Handle {
+        fibonacci::FibonacciHeap::insert(self, item)
+    }
+
+    fn peek(&self) -> Option<Self::Peek<'_>> {
+        fibonacci::FibonacciHeap::peek(self)
+    }
+
+    fn delete(&mut self) -> Option<T> {
+        self.delete_min()
+    }
+
+    fn meld(&mut self, other: Self) {
+        fibonacci::FibonacciHeap::meld(self, other)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::algorithms::heaps::binomial::BinomialHeap;
+    use crate::algorithms::heaps::fibonacci::FibonacciHeap;
+    use crate::algorithms::heaps::pairing::PairingHeap;
+    use crate::algorithms::heaps::Heap;
+    use crate::algorithms::priority_queue::PriorityQueue;
+
+    fn merge_worker_queues<H: Heap<u32>>(mut new: impl FnMut() -> H) -> Vec<u32> {
+        let mut merged = new();
+
+        for worker in 0..4 {
+            let mut queue = new();
+
+            for job in 0..25 {
+                queue.insert((job * 7 + worker * 13) % 100);
+            }
+
+            merged.meld(queue);
+        }
+
+        assert!(merged.len() == 100);
+        assert!(*merged.peek().unwrap() == 0);
+
+        let mut jobs = Vec::new();
+
+        while let Some(job) = merged.delete() {
+            jobs.push(job);
+        }
+
+        assert!(merged.is_empty());
+
+        jobs
+    }
+
+    #[test]
+    fn all_heaps_should_meld_and_delete_in_the_same_order() {
+        let expected = merge_worker_queues(|| PriorityQueue::new(false));
+
+        assert!(expected.windows(2).all(|w| w[0] <= w[1]));
+        assert!(merge_worker_queues(BinomialHeap::new) == expected);
+        assert!(merge_worker_queues(PairingHeap::new) == expected);
+        assert!(merge_worker_queues(FibonacciHeap::new) == expected);
+    }
+}
@@ -0,0 +1,223 @@
+use std::cell::Ref;
+use std::rc::Rc;
+
+pub use crate::algorithms::heaps::tree::Handle;
+use crate::algorithms::heaps::tree::{self, Link};
+use crate::algorithms::heaps::{HeapError, Owner};
+
+pub struct PairingHeap<T> {
+    root: Option<Link<T>>,
+    len: usize,
+    owner: Owner,
+}
+
+impl<T: Ord> PairingHeap<T> {
+    pub fn new() -> Self {
+        PairingHeap {
+            root: None,
+            len: 0,
+            owner: Owner::new(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.root.is_none()
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn peek(&self) -> Option<Ref<'_, T>> {
+        self.root
+            .as_ref()
+            .map(|root| Ref::map(root.borrow(), |node| &node.item))
+    }
+
+    pub fn insert(&mut self, item: T) -> Handle<T> {
+        let node = tree::node(item);
+        let handle = Handle(Rc::downgrade(&node), self.owner.clone());
+
+        self.push_root(node);
+        self.len += 1;
+
+        handle
+    }
+
+    pub fn meld(&mut self, mut other: Self) {
+        if let Some(root) = other.root.take() {
+            self.push_root(root);
+        }
+
+        self.len += other.len;
+        self.owner.absorb(&other.owner);
+    }
+
+    // Pairs up the children of the root from left to right, then links the
+    // pairs from right to left.
+    pub fn delete_min(&mut self) -> Option<T> {
+        let root = self.root.take()?;
+        let mut children = tree::take_children(&root).into_iter();
+        let mut pairs = Vec::new();
+
+        while let Some(a) = children.next() {
+            pairs.push(match children.next() {
+                Some(b) => tree::link(a, b),
+                None => a,
+            });
+        }
+
+        self.root = pairs.into_iter().rev().reduce(|acc, t| tree::link(t, acc));
+        self.len -= 1;
+
+        Some(tree::into_item(root))
+    }
+
+    pub fn decrease_key(&mut self, handle: &Handle<T>, key: T) -> Result<(), HeapError> {
+        if !self.owner.owns(&handle.1) {
+            return Err(HeapError::StaleHandle);
+        }
+
+        let node = handle.0.upgrade().ok_or(HeapError::StaleHandle)?;
+
+        if key > node.borrow().item {
+            return Err(HeapError::KeyIncreased);
+        }
+
+        node.borrow_mut().item = key;
+
+        let parent = node.borrow().parent.upgrade();
+
+        if let Some(parent) = parent {
+            if node.borrow().item < parent.borrow().item {
+                tree::cut(&parent, &node);
+                self.push_root(node);
+            }
+        }
+
+        Ok(())
+    }
+
+    fn push_root(&mut self, node: Link<T>) {
+        self.root = Some(match self.root.take() {
+            None => node,
+            Some(root) => tree::link(root, node),
+        });
+    }
+}
+
+impl<T: Ord> Default for PairingHeap<T> {
+    fn default() -> Self {
+        PairingHeap::new()
+    }
+}
+
+impl<T> Drop for PairingHeap<T> {
+    fn drop(&mut self) {
+        if let Some(root) = self.root.take() {
+            tree::release(root);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::algorithms::heaps::pairing::PairingHeap;
+    use crate::algorithms::heaps::HeapError;
+
+    #[test]
+    fn interface_operations_should_work_as_expected() {
+        let mut heap = PairingHeap::new();
+
+        assert!(heap.is_empty());
+        assert!(heap.delete_min() == None);
+        assert!(heap.peek().is_none());
+
+        for v in [5, 3, 8, 1, 9, 2, 7].iter() {
+            heap.insert(*v);
+        }
+
+        assert!(heap.len() == 7);
+        assert!(*heap.peek().unwrap() == 1);
+
+        let mut other = PairingHeap::new();
+        other.insert(0);
+        other.insert(6);
+        heap.meld(other);
+
+        assert!(heap.len() == 9);
+
+        for v in [0, 1, 2, 3, 5, 6, 7, 8, 9].iter() {
+            assert!(heap.delete_min() == Some(*v));
+        }
+
+        assert!(heap.is_empty());
+    }
+
+    #[test]
+    fn decrease_key_should_reorder_items_through_handles() {
+        let mut heap = PairingHeap::new();
+        let handles: Vec<_> = (0..100).map(|v| heap.insert(100 + v)).collect();
+
+        assert!(heap.delete_min() == Some(100));
+        assert!(heap.decrease_key(&handles[0], 0) == Err(HeapError::StaleHandle));
+        assert!(heap.decrease_key(&handles[1], 500) == Err(HeapError::KeyIncreased));
+
+        for (i, handle) in handles.iter().enumerate().skip(1) {
+            if i % 3 == 0 {
+                heap.decrease_key(handle, i as i32 - 100).unwrap();
+            }
+        }
+
+        let mut previous = i32::min_value();
+
+        for _ in 0..99 {
+            let v = heap.delete_min().unwrap();
+
+            assert!(v >= previous);
+
+            previous = v;
+        }
+
+        assert!(heap.is_empty());
+    }
+
+    #[test]
+    fn dropping_a_degenerate_heap_should_not_overflow_the_stack() {
+        let mut heap = PairingHeap::new();
+
+        for v in (0..200_000).rev() {
+            heap.insert(v);
+        }
+    }
+
+    #[test]
+    fn handles_should_only_work_on_the_heap_that_holds_their_item() {
+        let mut first = PairingHeap::new();
+        let mut second = PairingHeap::new();
+        let mut third = PairingHeap::new();
+
+        let a = first.insert(10);
+        let b = second.insert(20);
+        let c = third.insert(30);
+
+        assert!(first.decrease_key(&b, 1) == Err(HeapError::StaleHandle));
+        assert!(second.decrease_key(&a, 1) == Err(HeapError::StaleHandle));
+        assert!(*second.peek().unwrap() == 20);
+
+        second.meld(third);
+        first.meld(second);
+
+        assert!(first.decrease_key(&c, 3) == Ok(()));
+        assert!(first.decrease_key(&b, 2) == Ok(()));
+        assert!(first.decrease_key(&a, 1) == Ok(()));
+
+        let mut other = PairingHeap::new();
+        other.insert(0);
+
+        assert!(other.decrease_key(&a, -1) == Err(HeapError::StaleHandle));
+        assert!(first.delete_min() == Some(1));
+        assert!(first.delete_min() == Some(2));
+        assert!(first.delete_min() == Some(3));
+    }
+}
@@ -0,0 +1,103 @@
+use std::cell::RefCell;
+use std::mem;
+use std::rc::{Rc, Weak};
+
+use crate::algorithms::heaps::Owner;
+
+// Heap-ordered multiway tree shared by PairingHeap and FibonacciHeap. Parents
+// own their children, children point back with a weak reference, and every
+// node remembers its position in the parent's children so it can be cut out
+// in O(1).
+pub(crate) type Link<T> = Rc<RefCell<Node<T>>>;
+
+pub(crate) struct Node<T> {
+    pub(crate) item: T,
+    pub(crate) parent: Weak<RefCell<Node<T>>>,
+    pub(crate) children: Vec<Link<T>>,
+    pub(crate) index: usize,
+    pub(crate) marked: bool,
+}
+
+// Refers to an item for as long as it stays in the heap it was inserted into,
+// or in a heap that one was melded into.
+pub struct Handle<T>(pub(crate) Weak<RefCell<Node<T>>>, pub(crate) Owner);
+
+impl<T> Clone for Handle<T> {
+    fn clone(&self) -> Self {
+        Handle(self.0.clone(), self.1.clone())
+    }
+}
+
+pub(crate) fn node<T>(item: T) -> Link<T> {
+    Rc::new(RefCell::new(Node {
+        item,
+        parent: Weak::new(),
+        children: Vec::new(),
+        index: 0,
+        marked: false,
+    }))
+}
+
+// Makes the root with the larger item a child of the other one.
+pub(crate) fn link<T: Ord>(a: Link<T>, b: Link<T>) -> Link<T> {
+    let (parent, child) = if b.borrow().item < a.borrow().item {
+        (b, a)
+    } else {
+        (a, b)
+    };
+
+    {
+        let mut c = child.borrow_mut();
+        c.parent = Rc::downgrade(&parent);
+        c.index = parent.borrow().children.len();
+        c.marked = false;
+    }
+
+    parent.borrow_mut().children.push(child);
+
+    parent
+}
+
+pub(crate) fn cut<T>(parent: &Link<T>, child: &Link<T>) {
+    let index = child.borrow().index;
+
+    {
+        let mut p = parent.borrow_mut();
+        p.children.swap_remove(index);
+
+        if let Some(moved) = p.children.get(index) {
+            moved.borrow_mut().index = index;
+        }
+    }
+
+    let mut c = child.borrow_mut();
+    c.parent = Weak::new();
+    c.marked = false;
+}
+
+// Detaches the children of a root that is about to be removed.
+pub(crate) fn take_children<T>(root: &Link<T>) -> Vec<Link<T>> {
+    let children = mem::take(&mut root.borrow_mut().children);
+
+    for child in children.iter() {
+        let mut c = child.borrow_mut();
+        c.parent = Weak::new();
+        c.marked = false;
+    }
+
+    children
+}
+
+pub(crate) fn into_item<T>(root: Link<T>) -> T {
+    Rc::try_unwrap(root).ok().unwrap().into_inner().item
+}
+
+// Trees can degenerate into long paths, so they are torn down iteratively
+// rather than through the recursive drop of nested children.
+pub(crate) fn release<T>(root: Link<T>) {
+    let mut stack = vec![root];
+
+    while let Some(node) = stack.pop() {
+        stack.append(&mut node.borrow_mut().children);
+    }
+}
@@ -0,0 +1,336 @@
+use crate::algorithms::linked_list_stack::LinkedStack;
+
+pub trait Command<S> {
+    fn apply(&mut self, state: &mut S);
+
+    fn undo(&mut self, state: &mut S);
+}
+
+// Every entry on the undo and redo stacks is a group of commands. A group
+// holds a single command unless it was recorded inside a transaction. Groups
+// on the undo stack have their last command on top, groups on the redo stack
+// their first, so undoing and redoing are both plain pops. A bounded history
+// only lets the top `undoable` groups be undone; the forgotten ones below are
+// cut off in one split once they outnumber the limit, so trimming costs O(1)
+// amortised per command.
+pub struct History<S, Cmd> {
+    state: S,
+    undo: LinkedStack<LinkedStack<Cmd>>,
+    undoable: usize,
+    redo: LinkedStack<LinkedStack<Cmd>>,
+    limit: Option<usize>,
+    transaction: Option<LinkedStack<Cmd>>,
+    depth: usize,
+}
+
+impl<S, Cmd: Command<S>> History<S, Cmd> {
+    pub fn new(state: S) -> Self {
+        History {
+            state,
+            undo: LinkedStack::new(),
+            undoable: 0,
+            redo: LinkedStack::new(),
+            limit: None,
+            transaction: None,
+            depth: 0,
+        }
+    }
+
+    pub fn with_limit(state: S, limit: usize) -> Self {
+        let mut history = History::new(state);
+        history.limit = Some(limit);
+
+        history
+    }
+
+    pub fn state(&self) -> &S {
+        &self.state
+    }
+
+    pub fn into_state(self) -> S {
+        self.state
+    }
+
+    pub fn limit(&self) -> Option<usize> {
+        self.limit
+    }
+
+    pub fn undo_len(&self) -> usize {
+        self.undoable
+    }
+
+    pub fn redo_len(&self) -> usize {
+        self.redo.len()
+    }
+
+    pub fn can_undo(&self) -> bool {
+        self.transaction.is_none() && self.undoable > 0
+    }
+
+    pub fn can_redo(&self) -> bool {
+        self.transaction.is_none() && !self.redo.is_empty()
+    }
+
+    pub fn in_transaction(&self) -> bool {
+        self.transaction.is_some()
+    }
+
+    pub fn execute(&mut self, mut command: Cmd) {
+        command.apply(&mut self.state);
+        self.redo.clear();
+
+        match &mut self.transaction {
+            Some(group) => group.push(command),
+            None => {
+                let mut group = LinkedStack::new();
+                group.push(command);
+                self.record(group);
+            }
+        }
+    }
+
+    pub fn undo(&mut self) -> bool {
+        if !self.can_undo() {
+            return false;
+        }
+
+        let mut group = self.undo.pop().unwrap();
+        self.undoable -= 1;
+        let mut undone = LinkedStack::new();
+
+        while let Some(mut command) = group.pop() {
+            command.undo(&mut self.state);
+            undone.push(command);
+        }
+
+        self.redo.push(undone);
+
+        true
+    }
+
+    pub fn redo(&mut self) -> bool {
+        if !self.can_redo() {
+            return false;
+        }
+
+        let mut group = self.redo.pop().unwrap();
+        let mut redone = LinkedStack::new();
+
+        while let Some(mut command) = group.pop() {
+            command.apply(&mut self.state);
+            redone.push(command);
+        }
+
+        self.undo.push(redone);
+        self.undoable += 1;
+
+        true
+    }
+
+    // Transactions nest: only the outermost commit records a group.
+    pub fn begin_transaction(&mut self) {
+        if self.transaction.is_none() {
+            self.transaction = Some(LinkedStack::new());
+        }
+
+        self.depth += 1;
+    }
+
+    pub fn commit_transaction(&mut self) {
+        if self.depth == 0 {
+            return;
+        }
+
+        self.depth -= 1;
+
+        if self.depth == 0 {
+            let group = self.transaction.take().unwrap();
+
+            if !group.is_empty() {
+                self.record(group);
+            }
+        }
+    }
+
+    // Undoes everything executed since the outermost begin_transaction.
+    pub fn rollback_transaction(&mut self) {
+        if let Some(mut group) = self.transaction.take() {
+            while let Some(mut command) = group.pop() {
+                command.undo(&mut self.state);
+            }
+        }
+
+        self.depth = 0;
+    }
+
+    pub fn clear(&mut self) {
+        self.undo.clear();
+        self.undoable = 0;
+        self.redo.clear();
+    }
+
+    fn record(&mut self, group: LinkedStack<Cmd>) {
+        self.undo.push(group);
+        self.undoable += 1;
+
+        if let Some(limit) = self.limit {
+            self.undoable = self.undoable.min(limit);
+
+            if self.undo.len() > 2 * limit {
+                self.undo.split_off(self.undoable);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::algorithms::history::{Command, History};
+
+    enum Edit {
+        Insert(usize, char),
+        Delete(usize, Option<char>),
+    }
+
+    impl Command<String> for Edit {
+        fn apply(&mut self, state: &mut String) {
+            match self {
+                Edit::Insert(at, c) => state.insert(*at, *c),
+                Edit::Delete(at, removed) => *removed = Some(state.remove(*at)),
+            }
+        }
+
+        fn undo(&mut self, state: &mut String) {
+            match self {
+                Edit::Insert(at, _) => {
+                    state.remove(*at);
+                }
+                Edit::Delete(at, removed) => state.insert(*at, removed.take().unwrap()),
+            }
+        }
+    }
+
+    fn type_word(history: &mut History<String, Edit>, word: &str) {
+        let start = history.state().len();
+
+        for (i, c) in word.chars().enumerate() {
+            history.execute(Edit::Insert(start + i, c));
+        }
+    }
+
+    #[test]
+    fn undo_and_redo_should_restore_previous_states() {
+        let mut history = History::new(String::new());
+
+        assert!(!history.undo());
+        assert!(!history.redo());
+
+        type_word(&mut history, "abc");
+        history.execute(Edit::Delete(1, None));
+
+        assert!(history.state() == "ac");
+        assert!(history.undo_len() == 4);
+
+        assert!(history.undo());
+        assert!(history.state() == "abc");
+        assert!(history.undo());
+        assert!(history.state() == "ab");
+
+        assert!(history.redo());
+        assert!(history.state() == "abc");
+        assert!(history.redo());
+        assert!(history.state() == "ac");
+        assert!(!history.redo());
+
+        while history.undo() {}
+
+        assert!(history.state().is_empty());
+        assert!(history.redo_len() == 4);
+
+        history.execute(Edit::Insert(0, 'x'));
+
+        assert!(history.redo_len() == 0);
+        assert!(!history.can_redo());
+        assert!(history.into_state() == "x");
+    }
+
+    #[test]
+    fn transactions_should_be_undone_as_a_single_step() {
+        let mut history = History::new(String::new());
+
+        type_word(&mut history, "a");
+
+        history.begin_transaction();
+        type_word(&mut history, "bc");
+        history.begin_transaction();
+        type_word(&mut history, "de");
+        history.commit_transaction();
+
+        assert!(history.in_transaction());
+        assert!(!history.undo());
+
+        history.commit_transaction();
+
+        assert!(history.state() == "abcde");
+        assert!(history.undo_len() == 2);
+
+        assert!(history.undo());
+        assert!(history.state() == "a");
+        assert!(history.redo());
+        assert!(history.state() == "abcde");
+
+        history.begin_transaction();
+        type_word(&mut history, "fg");
+        history.execute(Edit::Delete(0, None));
+        history.rollback_transaction();
+
+        assert!(!history.in_transaction());
+        assert!(history.state() == "abcde");
+        assert!(history.undo_len() == 2);
+    }
+
+    #[test]
+    fn bounded_history_should_forget_the_oldest_steps() {
+        let mut history = History::with_limit(String::new(), 3);
+
+        type_word(&mut history, "abcde");
+
+        assert!(history.undo_len() == 3);
+
+        while history.undo() {}
+
+        assert!(history.state() == "ab");
+
+        while history.redo() {}
+
+        assert!(history.state() == "abcde");
+    }
+
+    #[test]
+    fn forgotten_steps_should_be_trimmed_in_batches() {
+        let mut history = History::with_limit(String::new(), 3);
+
+        for _ in 0..20 {
+            type_word(&mut history, "ab");
+            history.undo();
+
+            assert!(history.undo.len() <= 6);
+        }
+
+        assert!(history.undo_len() == 2);
+
+        type_word(&mut history, "xyz");
+
+        while history.undo() {}
+
+        assert!(history.state() == "aaaaaaaaaaaaaaaaaaaa");
+        assert!(history.redo_len() == 3);
+
+        let mut history = History::with_limit(String::new(), 0);
+
+        type_word(&mut history, "abc");
+
+        assert!(!history.undo());
+        assert!(history.undo.is_empty());
+    }
+}
@@ -0,0 +1,311 @@
+pub struct IndexMinPQ<T>(IndexHeap<T>);
+
+pub struct IndexMaxPQ<T>(IndexHeap<T>);
+
+// Heap of indices in 0..n laid out from position 1 like PriorityQueue. `heap`
+// maps a heap position to an index, `positions` maps an index back to its heap
+// position, and `keys` holds the key associated with each index.
+struct IndexHeap<T> {
+    heap: Vec<usize>,
+    positions: Vec<Option<usize>>,
+    keys: Vec<Option<T>>,
+    last: usize,
+    is_max: bool,
+}
+
+// IndexMinPQ and IndexMaxPQ only differ in the direction of their heap and
+// the names of the methods that reach its top.
+macro_rules! index_pq {
+    ($name:ident, $is_max:expr, $top_index:ident, $top_key:ident, $delete_top:ident) => {
+        impl<T: Ord> $name<T> {
+            pub fn new(n: usize) -> Self {
+                $name(IndexHeap::new(n, $is_max))
+            }
+
+            pub fn is_empty(&self) -> bool {
+                self.0.last == 0
+            }
+
+            pub fn len(&self) -> usize {
+                self.0.last
+            }
+
+            pub fn contains(&self, i: usize) -> bool {
+                self.0.positions[i].is_some()
+            }
+
+            pub fn insert(&mut self, i: usize, key: T) {
+                self.0.insert(i, key)
+            }
+
+            pub fn key_of(&self, i: usize) -> Option<&T> {
+                self.0.keys[i].as_ref()
+            }
+
+            pub fn change_key(&mut self, i: usize, key: T) -> Option<T> {
+                self.0.change_key(i, key)
+            }
+
+            pub fn decrease_key(&mut self, i: usize, key: T) {
+                assert!(self.contains(i), "index is not in the queue");
+                assert!(self.key_of(i).unwrap() > &key, "key does not decrease");
+
+                self.0.change_key(i, key);
+            }
+
+            pub fn increase_key(&mut self, i: usize, key: T) {
+                assert!(self.contains(i), "index is not in the queue");
+                assert!(self.key_of(i).unwrap() < &key, "key does not increase");
+
+                self.0.change_key(i, key);
+            }
+
+            pub fn delete(&mut self, i: usize) -> Option<T> {
+                self.0.delete(i)
+            }
+
+            pub fn $top_index(&self) -> Option<usize> {
+                self.0.top_index()
+            }
+
+            pub fn $top_key(&self) -> Option<&T> {
+                self.0.top_index().and_then(|i| self.key_of(i))
+            }
+
+            pub fn $delete_top(&mut self) -> Option<(usize, T)> {
+                self.0.delete_top()
+            }
+        }
+    };
+}
+
+index_pq!(IndexMinPQ, false, min_index, min_key, delete_min);
+index_pq!(IndexMaxPQ, true, max_index, max_key, delete_max);
+
+impl<T: Ord> IndexHeap<T> {
+    fn new(n: usize, is_max: bool) -> Self {
+        IndexHeap {
+            heap: vec![0; n + 1],
+            positions: vec![None; n],
+            keys: (0..n).map(|_| None).collect(),
+            last: 0,
+            is_max,
+        }
+    }
+
+    fn insert(&mut self, i: usize, key: T) {
+        assert!(self.positions[i].is_none(), "index is already present");
+
+        self.last += 1;
+        self.heap[self.last] = i;
+        self.positions[i] = Some(self.last);
+        self.keys[i] = Some(key);
+
+        self.swim(self.last);
+    }
+
+    fn change_key(&mut self, i: usize, key: T) -> Option<T> {
+        let k = self.positions[i]?;
+        let old = self.keys[i].replace(key);
+
+        self.swim(k);
+        self.sink(k);
+
+        old
+    }
+
+    fn delete(&mut self, i: usize) -> Option<T> {
+        let k = self.positions[i]?;
+
+        self.exchange(k, self.last);
+        self.last -= 1;
+
+        if k <= self.last {
+            self.swim(k);
+            self.sink(k);
+        }
+
+        self.positions[i] = None;
+        self.keys[i].take()
+    }
+
+    fn top_index(&self) -> Option<usize> {
+        if self.last == 0 {
+            None
+        } else {
+            Some(self.heap[1])
+        }
+    }
+
+    fn delete_top(&mut self) -> Option<(usize, T)> {
+        let i = self.top_index()?;
+
+        self.delete(i).map(|key| (i, key))
+    }
+
+    fn swim(&mut self, k: usize) {
+        let mut k = k;
+
+        while k > 1 && self.compare(k / 2, k) {
+            self.exchange(k, k / 2);
+            k /= 2;
+        }
+    }
+
+    fn sink(&mut self, k: usize) {
+        let mut k = k;
+
+        while 2 * k <= self.last {
+            let mut j = 2 * k;
+
+            if j < self.last && self.compare(j, j + 1) {
+                j += 1;
+            }
+
+            if !self.compare(k, j) {
+                break;
+            }
+
+            self.exchange(k, j);
+            k = j;
+        }
+    }
+
+    // Whether the key at heap position `a` belongs below the one at `b`.
+    fn compare(&self, a: usize, b: usize) -> bool {
+        let a = self.keys[self.heap[a]].as_ref().unwrap();
+        let b = self.keys[self.heap[b]].as_ref().unwrap();
+
+        if self.is_max {
+            a < b
+        } else {
+            a > b
+        }
+    }
+
+    fn exchange(&mut self, a: usize, b: usize) {
+        self.heap.swap(a, b);
+        self.positions[self.heap[a]] = Some(a);
+        self.positions[self.heap[b]] = Some(b);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::algorithms::index_priority_queue::{IndexMaxPQ, IndexMinPQ};
+
+    #[test]
+    fn interface_operations_for_index_min_priority_queue_should_work_as_expected() {
+        let mut queue = IndexMinPQ::new(10);
+
+        assert!(queue.is_empty());
+        assert!(queue.min_index() == None);
+        assert!(queue.delete_min() == None);
+
+        for (i, key) in [5, 3, 8, 1, 9, 2].iter().enumerate() {
+            queue.insert(i, *key);
+        }
+
+        assert!(queue.len() == 6);
+        assert!(queue.contains(3));
+        assert!(!queue.contains(7));
+        assert!(queue.min_index() == Some(3));
+        assert!(queue.key_of(2) == Some(&8));
+
+        queue.decrease_key(2, 0);
+
+        assert!(queue.min_index() == Some(2));
+
+        queue.increase_key(2, 10);
+
+        assert!(queue.min_key() == Some(&1));
+        assert!(queue.change_key(0, 4) == Some(5));
+        assert!(queue.change_key(7, 4) == None);
+        assert!(queue.delete(1) == Some(3));
+        assert!(queue.delete(1) == None);
+        assert!(!queue.contains(1));
+
+        assert!(queue.delete_min() == Some((3, 1)));
+        assert!(queue.delete_min() == Some((5, 2)));
+        assert!(queue.delete_min() == Some((0, 4)));
+        assert!(queue.delete_min() == Some((4, 9)));
+        assert!(queue.delete_min() == Some((2, 10)));
+        assert!(queue.delete_min() == None);
+        assert!(queue.is_empty());
+
+        queue.insert(1, 7);
+
+        assert!(queue.min_index() == Some(1));
+    }
+
+    #[test]
+    fn interface_operations_for_index_max_priority_queue_should_work_as_expected() {
+        let mut queue = IndexMaxPQ::new(100);
+
+        for i in 0..100 {
+            queue.insert(i, (i * 37) % 100);
+        }
+
+        for i in (0..100).step_by(2) {
+            queue.delete(i);
+        }
+
+        queue.increase_key(1, 1000);
+
+        assert!(queue.max_index() == Some(1));
+
+        queue.decrease_key(1, 37);
+
+        let mut previous = usize::max_value();
+
+        while let Some((i, key)) = queue.delete_max() {
+            assert!(i % 2 == 1);
+            assert!(key <= previous);
+
+            previous = key;
+        }
+    }
+
+    #[test]
+    fn index_min_priority_queue_should_merge_sorted_streams() {
+        let streams = vec![vec![1, 4, 9], vec![2, 3, 10, 11], vec![], vec![0, 5]];
+        let mut iters: Vec<_> = streams.iter().map(|s| s.iter()).collect();
+        let mut queue = IndexMinPQ::new(iters.len());
+
+        for (i, iter) in iters.iter_mut().enumerate() {
+            if let Some(key) = iter.next() {
+                queue.insert(i, *key);
+            }
+        }
+
+        let mut merged = Vec::new();
+
+        while let Some((i, key)) = queue.delete_min() {
+            merged.push(key);
+
+            if let Some(next) = iters[i].next() {
+                queue.insert(i, *next);
+            }
+        }
+
+        assert!(merged == vec![0, 1, 2, 3, 4, 5, 9, 10, 11]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn inserting_a_present_index_should_panic() {
+        let mut queue = IndexMinPQ::new(2);
+
+        queue.insert(0, 1);
+        queue.insert(0, 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "index is not in the queue")]
+    fn decreasing_the_key_of_an_absent_index_should_panic() {
+        let mut queue = IndexMinPQ::new(2);
+
+        queue.insert(0, 1);
+        queue.decrease_key(1, 0);
+    }
+}
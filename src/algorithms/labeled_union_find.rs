@@ -0,0 +1,239 @@
+use std::cell::Cell;
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::rc::Rc;
+
+use crate::algorithms::connectivity_quick_union_improved::halve_path;
+
+// Weighted quick-union with path halving over labels that are given ids as
+// they are added. The members of every component are also threaded on a
+// circular list through `next`, and a union splices the two lists together.
+pub struct LabeledUnionFind<T> {
+    ids: HashMap<Rc<T>, usize>,
+    labels: Vec<Rc<T>>,
+    tree: Vec<Cell<usize>>,
+    sizes: Vec<usize>,
+    next: Vec<usize>,
+    count: usize,
+}
+
+pub struct Members<'a, T> {
+    set: &'a LabeledUnionFind<T>,
+    start: usize,
+    current: Option<usize>,
+}
+
+impl<T: Hash + Eq> LabeledUnionFind<T> {
+    pub fn new() -> Self {
+        LabeledUnionFind {
+            ids: HashMap::new(),
+            labels: Vec::new(),
+            tree: Vec::new(),
+            sizes: Vec::new(),
+            next: Vec::new(),
+            count: 0,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.labels.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.labels.len()
+    }
+
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    pub fn contains(&self, label: &T) -> bool {
+        self.ids.contains_key(label)
+    }
+
+    // Adds the label as a component of its own. Returns false if it was
+    // already present.
+    pub fn add(&mut self, label: T) -> bool {
+        if self.contains(&label) {
+            return false;
+        }
+
+        self.insert(label);
+
+        true
+    }
+
+    // Merges the components of both labels, adding any that are new. Returns
+    // false if they were already connected.
+    pub fn union(&mut self, p: T, q: T) -> bool {
+        let p = self.id_or_insert(p);
+        let q = self.id_or_insert(q);
+
+        let root_p = self.root(p);
+        let root_q = self.root(q);
+
+        if root_p == root_q {
+            return false;
+        }
+
+        if self.sizes[root_p] < self.sizes[root_q] {
+            self.tree[root_p].set(root_q);
+            self.sizes[root_q] += self.sizes[root_p];
+        } else {
+            self.tree[root_q].set(root_p);
+            self.sizes[root_p] += self.sizes[root_q];
+        }
+
+        self.next.swap(root_p, root_q);
+        self.count -= 1;
+
+        true
+    }
+
+    // Unknown labels are not connected to anything, not even themselves.
+    pub fn connected(&self, p: &T, q: &T) -> bool {
+        match (self.ids.get(p), self.ids.get(q)) {
+            (Some(&p), Some(&q)) => self.root(p) == self.root(q),
+            _ => false,
+        }
+    }
+
+    // The label that currently represents the component.
+    pub fn find(&self, label: &T) -> Option<&T> {
+        let id = *self.ids.get(label)?;
+
+        Some(&self.labels[self.root(id)])
+    }
+
+    pub fn component_size(&self, label: &T) -> Option<usize> {
+        let id = *self.ids.get(label)?;
+
+        Some(self.sizes[self.root(id)])
+    }
+
+    // Every label in the component, starting with the given one.
+    pub fn members(&self, label: &T) -> Option<Members<'_, T>> {
+        let id = *self.ids.get(label)?;
+
+        Some(Members {
+            set: self,
+            start: id,
+            current: Some(id),
+        })
+    }
+
+    fn id_or_insert(&mut self, label: T) -> usize {
+        match self.ids.get(&label) {
+            Some(&id) => id,
+            None => self.insert(label),
+        }
+    }
+
+    fn insert(&mut self, label: T) -> usize {
+        let id = self.labels.len();
+        let label = Rc::new(label);
+
+        self.ids.insert(Rc::clone(&label), id);
+        self.labels.push(label);
+        self.tree.push(Cell::new(id));
+        self.sizes.push(1);
+        self.next.push(id);
+        self.count += 1;
+
+        id
+    }
+
+    fn root(&self, node: usize) -> usize {
+        halve_path(&self.tree, node, |_, _| {})
+    }
+}
+
+impl<T: Hash + Eq> Default for LabeledUnionFind<T> {
+    fn default() -> Self {
+        LabeledUnionFind::new()
+    }
+}
+
+impl<'a, T> Iterator for Members<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let current = self.current?;
+        let next = self.set.next[current];
+
+        self.current = if next == self.start { None } else { Some(next) };
+
+        Some(&self.set.labels[current])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::algorithms::labeled_union_find::LabeledUnionFind;
+
+    #[test]
+    fn interface_operations_should_work_as_expected() {
+        let mut set = LabeledUnionFind::new();
+
+        assert!(set.is_empty());
+        assert!(set.add("a"));
+        assert!(!set.add("a"));
+        assert!(set.connected(&"a", &"a"));
+        assert!(!set.connected(&"z", &"z"));
+        assert!(set.component_size(&"a") == Some(1));
+        assert!(set.component_size(&"z") == None);
+        assert!(set.members(&"z").is_none());
+
+        assert!(set.union("a", "b"));
+        assert!(set.union("c", "d"));
+        assert!(set.union("e", "c"));
+        assert!(!set.union("d", "e"));
+
+        assert!(set.len() == 5);
+        assert!(set.count() == 2);
+        assert!(set.connected(&"a", &"b"));
+        assert!(set.connected(&"d", &"e"));
+        assert!(!set.connected(&"a", &"c"));
+        assert!(!set.connected(&"a", &"z"));
+        assert!(set.component_size(&"e") == Some(3));
+        assert!(set.find(&"b") == set.find(&"a"));
+
+        assert!(set.union("b", "d"));
+
+        assert!(set.count() == 1);
+        assert!(set.component_size(&"a") == Some(5));
+    }
+
+    #[test]
+    fn members_should_enumerate_every_label_of_the_component_once() {
+        let mut set = LabeledUnionFind::new();
+
+        for i in 0..100 {
+            set.union(format!("user-{}", i), format!("user-{}", i % 7));
+        }
+
+        set.add(String::from("loner"));
+
+        assert!(set.count() == 8);
+
+        for i in 0..7 {
+            let label = format!("user-{}", i);
+            let mut members: Vec<String> = set.members(&label).unwrap().cloned().collect();
+
+            members.sort();
+
+            let mut expected: Vec<String> =
+                (i..100).step_by(7).map(|j| format!("user-{}", j)).collect();
+
+            expected.sort();
+
+            assert!(members == expected);
+            assert!(set.component_size(&label) == Some(expected.len()));
+            assert!(set.members(&label).unwrap().next() == Some(&label));
+        }
+
+        let loner = String::from("loner");
+
+        assert!(set.members(&loner).unwrap().eq(vec![&loner]));
+    }
+}
@@ -1,8 +1,16 @@
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::iter::FromIterator;
+use std::mem;
+use std::ops::RangeBounds;
 use std::ptr;
 
+use crate::algorithms::linked_list_stack::bounds;
+
 pub struct LinkedQueue<T> {
     head: Link<T>,
     tail: *mut Node<T>,
+    len: usize,
 }
 
 type Link<T> = Option<Box<Node<T>>>;
@@ -22,11 +30,21 @@ pub struct IterMut<'a, T> {
     next: Option<&'a mut Node<T>>,
 }
 
+pub struct CursorMut<'a, T> {
+    queue: &'a mut LinkedQueue<T>,
+    prev: *mut Node<T>,
+    index: usize,
+}
+
+unsafe impl<T: Send> Send for LinkedQueue<T> {}
+unsafe impl<T: Sync> Sync for LinkedQueue<T> {}
+
 impl<T> LinkedQueue<T> {
     pub fn new() -> Self {
         LinkedQueue {
             head: None,
             tail: ptr::null_mut(),
+            len: 0,
         }
     }
 
@@ -48,6 +66,7 @@ impl<T> LinkedQueue<T> {
         }
 
         self.tail = raw_tail;
+        self.len += 1;
     }
 
     pub fn dequeue(&mut self) -> Option<T> {
@@ -59,12 +78,92 @@ impl<T> LinkedQueue<T> {
                 self.tail = ptr::null_mut();
             }
 
+            self.len -= 1;
             head.item
         })
     }
 
-    pub fn into_iter(self) -> IntoIter<T> {
-        IntoIter(self)
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn peek(&self) -> Option<&T> {
+        self.head.as_ref().map(|node| &node.item)
+    }
+
+    pub fn peek_mut(&mut self) -> Option<&mut T> {
+        self.head.as_mut().map(|node| &mut node.item)
+    }
+
+    pub fn clear(&mut self) {
+        let mut cur_link = self.head.take();
+        while let Some(mut boxed_node) = cur_link {
+            cur_link = boxed_node.next.take();
+        }
+
+        self.tail = ptr::null_mut();
+        self.len = 0;
+    }
+
+    pub fn append(&mut self, other: &mut Self) {
+        if other.is_empty() {
+            return;
+        }
+
+        if self.tail.is_null() {
+            self.head = other.head.take();
+        } else {
+            unsafe {
+                (*self.tail).next = other.head.take();
+            }
+        }
+
+        self.tail = other.tail;
+        self.len += other.len;
+
+        other.tail = ptr::null_mut();
+        other.len = 0;
+    }
+
+    pub fn split_off(&mut self, at: usize) -> Self {
+        assert!(at <= self.len, "split index out of bounds");
+
+        if at == 0 {
+            return mem::take(self);
+        }
+
+        let mut cursor = self.cursor_front_mut();
+        for _ in 1..at {
+            cursor.move_next();
+        }
+
+        cursor.split_after()
+    }
+
+    pub fn drain<R: RangeBounds<usize>>(&mut self, range: R) -> IntoIter<T> {
+        let (start, end) = bounds(range, self.len);
+
+        let mut middle = self.split_off(start);
+        let mut rest = middle.split_off(end - start);
+
+        self.append(&mut rest);
+
+        IntoIter(middle)
+    }
+
+    pub fn retain<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&T) -> bool,
+    {
+        let mut cursor = self.cursor_front_mut();
+
+        while let Some(item) = cursor.current() {
+            if f(item) {
+                cursor.move_next();
+            } else {
+                cursor.remove_current();
+            }
+        }
     }
 
     pub fn iter(&self) -> Iter<'_, T> {
@@ -78,17 +177,281 @@ impl<T> LinkedQueue<T> {
             next: self.head.as_mut().map(|node| &mut **node),
         }
     }
+
+    pub fn cursor_front_mut(&mut self) -> CursorMut<'_, T> {
+        CursorMut {
+            queue: self,
+            prev: ptr::null_mut(),
+            index: 0,
+        }
+    }
+}
+
+// The cursor sits on the node owned by `prev.next`, or by the queue's head
+// when `prev` is null. Past the last node it points to a "ghost" position,
+// from which moving forward wraps around to the front.
+impl<'a, T> CursorMut<'a, T> {
+    pub fn index(&self) -> Option<usize> {
+        if self.link().is_some() {
+            Some(self.index)
+        } else {
+            None
+        }
+    }
+
+    pub fn current(&mut self) -> Option<&mut T> {
+        self.link_mut().as_mut().map(|node| &mut node.item)
+    }
+
+    pub fn peek_next(&mut self) -> Option<&mut T> {
+        let link = if self.link().is_none() {
+            &mut self.queue.head
+        } else {
+            &mut self.link_mut().as_mut().unwrap().next
+        };
+
+        link.as_mut().map(|node| &mut node.item)
+    }
+
+    pub fn move_next(&mut self) {
+        match self.link_mut().as_mut() {
+            None => {
+                self.prev = ptr::null_mut();
+                self.index = 0;
+            }
+            Some(node) => {
+                self.prev = &mut **node;
+                self.index += 1;
+            }
+        }
+    }
+
+    pub fn remove_current(&mut self) -> Option<T> {
+        let node = self.link_mut().take()?;
+        let node = *node;
+
+        *self.link_mut() = node.next;
+
+        if self.link().is_none() {
+            self.queue.tail = self.prev;
+        }
+
+        self.queue.len -= 1;
+
+        Some(node.item)
+    }
+
+    pub fn insert_before(&mut self, item: T) {
+        let mut new_node = Box::new(Node {
+            item,
+            next: self.link_mut().take(),
+        });
+        let raw_node: *mut _ = &mut *new_node;
+
+        if new_node.next.is_none() {
+            self.queue.tail = raw_node;
+        }
+
+        *self.link_mut() = Some(new_node);
+
+        self.prev = raw_node;
+        self.index += 1;
+        self.queue.len += 1;
+    }
+
+    pub fn insert_after(&mut self, item: T) {
+        let is_ghost = self.link().is_none();
+        let link = if is_ghost {
+            &mut self.queue.head
+        } else {
+            &mut self.link_mut().as_mut().unwrap().next
+        };
+
+        let mut new_node = Box::new(Node {
+            item,
+            next: link.take(),
+        });
+        let raw_node: *mut _ = &mut *new_node;
+        let is_last = new_node.next.is_none();
+
+        *link = Some(new_node);
+
+        if is_last {
+            self.queue.tail = raw_node;
+
+            if is_ghost {
+                self.prev = raw_node;
+            }
+        }
+
+        // From the ghost the new node goes in at the head, before the cursor.
+        if is_ghost {
+            self.index += 1;
+        }
+
+        self.queue.len += 1;
+    }
+
+    pub fn split_before(&mut self) -> LinkedQueue<T> {
+        if self.prev.is_null() {
+            return LinkedQueue::new();
+        }
+
+        let rest = unsafe { (*self.prev).next.take() };
+        let front = LinkedQueue {
+            head: self.queue.head.take(),
+            tail: self.prev,
+            len: self.index,
+        };
+
+        self.queue.head = rest;
+        self.queue.len -= self.index;
+
+        if self.queue.head.is_none() {
+            self.queue.tail = ptr::null_mut();
+        }
+
+        self.prev = ptr::null_mut();
+        self.index = 0;
+
+        front
+    }
+
+    pub fn split_after(&mut self) -> LinkedQueue<T> {
+        let index = self.index;
+        let len = self.queue.len;
+
+        match self.link_mut() {
+            None => {
+                self.prev = ptr::null_mut();
+                self.index = 0;
+
+                mem::take(self.queue)
+            }
+            Some(node) => {
+                let raw_node: *mut Node<T> = &mut **node;
+                let rest = node.next.take();
+
+                let back = match rest {
+                    None => LinkedQueue::new(),
+                    Some(_) => LinkedQueue {
+                        head: rest,
+                        tail: self.queue.tail,
+                        len: len - index - 1,
+                    },
+                };
+
+                self.queue.tail = raw_node;
+                self.queue.len = index + 1;
+
+                back
+            }
+        }
+    }
+
+    fn link(&self) -> &Link<T> {
+        if self.prev.is_null() {
+            &self.queue.head
+        } else {
+            unsafe { &(*self.prev).next }
+        }
+    }
+
+    fn link_mut(&mut self) -> &mut Link<T> {
+        if self.prev.is_null() {
+            &mut self.queue.head
+        } else {
+            unsafe { &mut (*self.prev).next }
+        }
+    }
 }
 
 impl<T> Drop for LinkedQueue<T> {
     fn drop(&mut self) {
-        let mut cur_link = self.head.take();
-        while let Some(mut boxed_node) = cur_link {
-            cur_link = boxed_node.next.take();
+        self.clear();
+    }
+}
+
+impl<T> Default for LinkedQueue<T> {
+    fn default() -> Self {
+        LinkedQueue::new()
+    }
+}
+
+impl<T: Clone> Clone for LinkedQueue<T> {
+    fn clone(&self) -> Self {
+        self.iter().cloned().collect()
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for LinkedQueue<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T: PartialEq> PartialEq for LinkedQueue<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len() == other.len() && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: Eq> Eq for LinkedQueue<T> {}
+
+impl<T: Hash> Hash for LinkedQueue<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.len().hash(state);
+
+        for item in self.iter() {
+            item.hash(state);
         }
     }
 }
 
+impl<T> FromIterator<T> for LinkedQueue<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut queue = LinkedQueue::new();
+        queue.extend(iter);
+
+        queue
+    }
+}
+
+impl<T> Extend<T> for LinkedQueue<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for item in iter {
+            self.enqueue(item);
+        }
+    }
+}
+
+impl<T> IntoIterator for LinkedQueue<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter(self)
+    }
+}
+
+impl<'a, T> IntoIterator for &'a LinkedQueue<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut LinkedQueue<T> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
 impl<T> Iterator for IntoIter<T> {
     type Item = T;
     fn next(&mut self) -> Option<Self::Item> {
@@ -265,4 +628,347 @@ mod tests {
             assert!(*v == 0);
         }
     }
+
+    #[test]
+    fn peek_len_and_clear_should_work_as_expected() {
+        let mut queue = LinkedQueue::<u32>::new();
+
+        assert!(queue.len() == 0);
+        assert!(queue.peek() == None);
+        assert!(queue.peek_mut() == None);
+
+        for i in 0..10 {
+            queue.enqueue(i);
+        }
+
+        assert!(queue.len() == 10);
+        assert!(queue.peek() == Some(&0));
+
+        *queue.peek_mut().unwrap() = 100;
+
+        assert!(queue.dequeue() == Some(100));
+        assert!(queue.len() == 9);
+
+        queue.clear();
+
+        assert!(queue.is_empty());
+        assert!(queue.len() == 0);
+        assert!(queue.peek() == None);
+
+        queue.enqueue(1);
+        queue.enqueue(2);
+
+        assert!(queue.dequeue() == Some(1));
+        assert!(queue.dequeue() == Some(2));
+    }
+
+    #[test]
+    fn standard_traits_should_be_implemented() {
+        use std::collections::hash_map::DefaultHasher;
+        use std::hash::{Hash, Hasher};
+
+        let mut queue: LinkedQueue<u32> = (0..5).collect();
+
+        assert_eq!(format!("{:?}", queue), "[0, 1, 2, 3, 4]");
+
+        let copy = queue.clone();
+
+        assert_eq!(queue, copy);
+        assert_eq!(LinkedQueue::<u32>::default(), LinkedQueue::new());
+
+        let mut h0 = DefaultHasher::new();
+        let mut h1 = DefaultHasher::new();
+        queue.hash(&mut h0);
+        copy.hash(&mut h1);
+
+        assert!(h0.finish() == h1.finish());
+
+        queue.extend(vec![5, 6]);
+
+        assert!(queue != copy);
+        assert!(queue.len() == 7);
+
+        for v in &mut queue {
+            *v += 1;
+        }
+
+        let mut k = 1;
+
+        for v in &queue {
+            assert!(*v == k);
+
+            k += 1;
+        }
+
+        let items: Vec<u32> = queue.into_iter().collect();
+
+        assert!(items == vec![1, 2, 3, 4, 5, 6, 7]);
+    }
+
+    #[test]
+    fn cursor_should_walk_and_edit_the_queue() {
+        let mut queue: LinkedQueue<u32> = (0..5).collect();
+        let mut cursor = queue.cursor_front_mut();
+
+        assert!(cursor.index() == Some(0));
+        assert!(cursor.current() == Some(&mut 0));
+        assert!(cursor.peek_next() == Some(&mut 1));
+
+        cursor.move_next();
+        cursor.move_next();
+
+        assert!(cursor.index() == Some(2));
+        assert!(cursor.remove_current() == Some(2));
+        assert!(cursor.current() == Some(&mut 3));
+
+        cursor.insert_before(20);
+        cursor.insert_after(30);
+
+        assert!(cursor.index() == Some(3));
+        assert!(cursor.current() == Some(&mut 3));
+
+        cursor.move_next();
+        cursor.move_next();
+
+        assert!(cursor.current() == Some(&mut 4));
+        assert!(cursor.remove_current() == Some(4));
+        assert!(cursor.current() == None);
+        assert!(cursor.index() == None);
+
+        cursor.insert_before(40);
+
+        assert!(queue.iter().cloned().collect::<Vec<_>>() == vec![0, 1, 20, 3, 30, 40]);
+        assert!(queue.len() == 6);
+
+        queue.enqueue(50);
+
+        assert!(queue.iter().last() == Some(&50));
+
+        let mut cursor = queue.cursor_front_mut();
+
+        while cursor.current().is_some() {
+            cursor.move_next();
+        }
+
+        cursor.insert_after(-1i32 as u32);
+        cursor.move_next();
+
+        assert!(cursor.current() == Some(&mut (-1i32 as u32)));
+        assert!(queue.dequeue() == Some(-1i32 as u32));
+    }
+
+    #[test]
+    fn cursor_should_support_in_place_filtering_and_lru_reordering() {
+        let mut queue: LinkedQueue<u32> = (0..20).collect();
+        let mut cursor = queue.cursor_front_mut();
+
+        while let Some(v) = cursor.current() {
+            if *v % 3 == 0 {
+                cursor.remove_current();
+            } else {
+                cursor.move_next();
+            }
+        }
+
+        assert!(queue.iter().all(|v| *v % 3 != 0));
+        assert!(queue.len() == 13);
+
+        queue.enqueue(100);
+
+        assert!(queue.dequeue() == Some(1));
+
+        let mut cursor = queue.cursor_front_mut();
+
+        while cursor.current() != Some(&mut 10) {
+            cursor.move_next();
+        }
+
+        let used = cursor.remove_current().unwrap();
+        queue.enqueue(used);
+
+        assert!(
+            queue.iter().cloned().collect::<Vec<_>>()
+                == vec![2, 4, 5, 7, 8, 11, 13, 14, 16, 17, 19, 100, 10]
+        );
+
+        let mut cursor = queue.cursor_front_mut();
+
+        while cursor.current().is_some() {
+            cursor.remove_current();
+        }
+
+        assert!(queue.is_empty());
+
+        queue.enqueue(1);
+
+        assert!(queue.dequeue() == Some(1));
+    }
+
+    #[test]
+    fn cursor_should_split_the_queue() {
+        let mut queue: LinkedQueue<u32> = (0..10).collect();
+        let mut cursor = queue.cursor_front_mut();
+
+        for _ in 0..4 {
+            cursor.move_next();
+        }
+
+        let mut front = cursor.split_before();
+
+        assert!(cursor.index() == Some(0));
+        assert!(cursor.current() == Some(&mut 4));
+
+        cursor.move_next();
+
+        let mut back = cursor.split_after();
+
+        assert!(front.iter().cloned().collect::<Vec<_>>() == vec![0, 1, 2, 3]);
+        assert!(queue.iter().cloned().collect::<Vec<_>>() == vec![4, 5]);
+        assert!(back.iter().cloned().collect::<Vec<_>>() == vec![6, 7, 8, 9]);
+        assert!(front.len() + queue.len() + back.len() == 10);
+
+        front.enqueue(10);
+        queue.enqueue(11);
+        back.enqueue(12);
+
+        assert!(front.iter().last() == Some(&10));
+        assert!(queue.iter().last() == Some(&11));
+        assert!(back.iter().last() == Some(&12));
+
+        let mut cursor = back.cursor_front_mut();
+
+        assert!(cursor.split_before().is_empty());
+
+        while cursor.current().is_some() {
+            cursor.move_next();
+        }
+
+        let all = cursor.split_after();
+
+        assert!(back.is_empty());
+        assert!(all.len() == 5);
+    }
+
+    #[test]
+    fn insert_after_on_the_ghost_should_keep_split_lengths_right() {
+        let mut queue: LinkedQueue<u32> = (0..3).collect();
+        let mut cursor = queue.cursor_front_mut();
+
+        while cursor.current().is_some() {
+            cursor.move_next();
+        }
+
+        cursor.insert_after(9);
+
+        assert!(cursor.index() == None);
+        assert!(cursor.peek_next() == Some(&mut 9));
+
+        let front = cursor.split_before();
+
+        assert!(front.iter().cloned().collect::<Vec<_>>() == vec![9, 0, 1, 2]);
+        assert!(front.len() == 4);
+        assert!(queue.is_empty());
+        assert!(queue.len() == 0);
+
+        let mut queue = LinkedQueue::new();
+        let mut cursor = queue.cursor_front_mut();
+
+        cursor.insert_after(5);
+
+        let front = cursor.split_before();
+
+        assert!(front.iter().cloned().collect::<Vec<_>>() == vec![5]);
+        assert!(front.len() == 1);
+        assert!(queue.is_empty());
+        assert!(queue.len() == 0);
+
+        let mut queue: LinkedQueue<u32> = (0..3).collect();
+        let mut cursor = queue.cursor_front_mut();
+
+        while cursor.current().is_some() {
+            cursor.move_next();
+        }
+
+        cursor.insert_after(9);
+
+        let all = cursor.split_after();
+
+        assert!(all.iter().cloned().collect::<Vec<_>>() == vec![9, 0, 1, 2]);
+        assert!(all.len() == 4);
+        assert!(queue.len() == 0);
+
+        let mut queue = LinkedQueue::new();
+        let mut cursor = queue.cursor_front_mut();
+
+        cursor.insert_after(5);
+
+        let all = cursor.split_after();
+
+        assert!(all.len() == 1);
+        assert!(queue.len() == 0);
+    }
+
+    #[test]
+    fn append_should_concatenate_queues_in_constant_time() {
+        let mut queue: LinkedQueue<u32> = (0..3).collect();
+        let mut other: LinkedQueue<u32> = (3..6).collect();
+
+        queue.append(&mut other);
+
+        assert!(other.is_empty());
+        assert!(queue.len() == 6);
+        assert!(queue.iter().cloned().collect::<Vec<_>>() == vec![0, 1, 2, 3, 4, 5]);
+
+        queue.enqueue(6);
+        other.enqueue(7);
+        other.append(&mut LinkedQueue::new());
+
+        let mut empty = LinkedQueue::new();
+        empty.append(&mut queue);
+        empty.append(&mut other);
+
+        assert!(queue.is_empty());
+        assert!(empty.iter().cloned().collect::<Vec<_>>() == vec![0, 1, 2, 3, 4, 5, 6, 7]);
+
+        empty.enqueue(8);
+
+        assert!(empty.iter().last() == Some(&8));
+        assert!(empty.len() == 9);
+    }
+
+    #[test]
+    fn split_off_drain_and_retain_should_work_as_expected() {
+        let mut queue: LinkedQueue<u32> = (0..10).collect();
+        let mut back = queue.split_off(4);
+
+        assert!(queue.iter().cloned().collect::<Vec<_>>() == vec![0, 1, 2, 3]);
+        assert!(back.iter().cloned().collect::<Vec<_>>() == vec![4, 5, 6, 7, 8, 9]);
+        assert!(queue.split_off(4).is_empty());
+
+        queue.append(&mut back);
+
+        let drained: Vec<u32> = queue.drain(2..=4).collect();
+
+        assert!(drained == vec![2, 3, 4]);
+        assert!(queue.iter().cloned().collect::<Vec<_>>() == vec![0, 1, 5, 6, 7, 8, 9]);
+        assert!(queue.len() == 7);
+
+        queue.retain(|v| v % 2 == 1);
+
+        assert!(queue.iter().cloned().collect::<Vec<_>>() == vec![1, 5, 7, 9]);
+        assert!(queue.len() == 4);
+
+        queue.enqueue(11);
+
+        assert!(queue.iter().last() == Some(&11));
+
+        let all: Vec<u32> = queue.drain(..).collect();
+
+        assert!(all == vec![1, 5, 7, 9, 11]);
+        assert!(queue.is_empty());
+
+        queue.enqueue(1);
+
+        assert!(queue.dequeue() == Some(1));
+    }
 }
@@ -1,5 +1,14 @@
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::iter::FromIterator;
+use std::mem;
+use std::ops::{Bound, RangeBounds};
+use std::ptr;
+
 pub struct LinkedStack<T> {
     head: Link<T>,
+    bottom: *mut Node<T>,
+    len: usize,
 }
 
 type Link<T> = Option<Box<Node<T>>>;
@@ -19,9 +28,16 @@ pub struct IterMut<'a, T> {
     next: Option<&'a mut Node<T>>,
 }
 
+unsafe impl<T: Send> Send for LinkedStack<T> {}
+unsafe impl<T: Sync> Sync for LinkedStack<T> {}
+
 impl<T> LinkedStack<T> {
     pub fn new() -> Self {
-        LinkedStack { head: None }
+        LinkedStack {
+            head: None,
+            bottom: ptr::null_mut(),
+            len: 0,
+        }
     }
 
     pub fn is_empty(&self) -> bool {
@@ -32,12 +48,17 @@ impl<T> LinkedStack<T> {
     }
 
     pub fn push(&mut self, item: T) {
-        let new_node = Box::new(Node {
+        let mut new_node = Box::new(Node {
             item,
             next: self.head.take(),
         });
 
+        if new_node.next.is_none() {
+            self.bottom = &mut *new_node;
+        }
+
         self.head = Some(new_node);
+        self.len += 1;
     }
 
     pub fn pop(&mut self) -> Option<T> {
@@ -45,13 +66,130 @@ impl<T> LinkedStack<T> {
             None => None,
             Some(node) => {
                 self.head = node.next;
+                self.len -= 1;
+
+                if self.head.is_none() {
+                    self.bottom = ptr::null_mut();
+                }
+
                 Some(node.item)
             }
         }
     }
 
-    pub fn into_iter(self) -> ConsumerIter<T> {
-        ConsumerIter(self)
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn peek(&self) -> Option<&T> {
+        self.head.as_ref().map(|node| &node.item)
+    }
+
+    pub fn peek_mut(&mut self) -> Option<&mut T> {
+        self.head.as_mut().map(|node| &mut node.item)
+    }
+
+    pub fn clear(&mut self) {
+        let mut current_link = self.head.take();
+        while let Some(mut boxed_node) = current_link {
+            current_link = boxed_node.next.take();
+        }
+
+        self.bottom = ptr::null_mut();
+        self.len = 0;
+    }
+
+    // Moves every item of `other` on top of this stack, keeping their order.
+    pub fn append(&mut self, other: &mut Self) {
+        if other.is_empty() {
+            return;
+        }
+
+        unsafe {
+            (*other.bottom).next = self.head.take();
+        }
+
+        if self.bottom.is_null() {
+            self.bottom = other.bottom;
+        }
+
+        self.head = other.head.take();
+        self.len += other.len;
+
+        other.bottom = ptr::null_mut();
+        other.len = 0;
+    }
+
+    // Positions follow iteration order, so the first `at` items from the top
+    // stay and the ones below them are returned.
+    pub fn split_off(&mut self, at: usize) -> Self {
+        assert!(at <= self.len, "split index out of bounds");
+
+        if at == 0 {
+            return mem::take(self);
+        }
+
+        let mut node = self.head.as_deref_mut().unwrap();
+        for _ in 1..at {
+            node = node.next.as_deref_mut().unwrap();
+        }
+
+        let rest = match node.next.take() {
+            None => return LinkedStack::new(),
+            Some(rest) => rest,
+        };
+
+        let split = LinkedStack {
+            head: Some(rest),
+            bottom: self.bottom,
+            len: self.len - at,
+        };
+
+        self.bottom = node;
+        self.len = at;
+
+        split
+    }
+
+    pub fn drain<R: RangeBounds<usize>>(&mut self, range: R) -> ConsumerIter<T> {
+        let (start, end) = bounds(range, self.len);
+
+        let mut middle = self.split_off(start);
+        let mut rest = middle.split_off(end - start);
+
+        rest.append(self);
+        mem::swap(self, &mut rest);
+
+        ConsumerIter(middle)
+    }
+
+    pub fn retain<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&T) -> bool,
+    {
+        let mut current_link = self.head.take();
+
+        self.bottom = ptr::null_mut();
+        self.len = 0;
+
+        while let Some(mut node) = current_link {
+            current_link = node.next.take();
+
+            if f(&node.item) {
+                let raw_node: *mut _ = &mut *node;
+
+                if self.bottom.is_null() {
+                    self.head = Some(node);
+                } else {
+                    unsafe {
+                        (*self.bottom).next = Some(node);
+                    }
+                }
+
+                self.bottom = raw_node;
+                self.len += 1;
+            }
+        }
     }
 
     pub fn iter(&self) -> Iter<'_, T> {
@@ -67,6 +205,24 @@ impl<T> LinkedStack<T> {
     }
 }
 
+pub(crate) fn bounds<R: RangeBounds<usize>>(range: R, len: usize) -> (usize, usize) {
+    let start = match range.start_bound() {
+        Bound::Included(start) => *start,
+        Bound::Excluded(start) => start + 1,
+        Bound::Unbounded => 0,
+    };
+
+    let end = match range.end_bound() {
+        Bound::Included(end) => end + 1,
+        Bound::Excluded(end) => *end,
+        Bound::Unbounded => len,
+    };
+
+    assert!(start <= end && end <= len, "drain range out of bounds");
+
+    (start, end)
+}
+
 impl<'a, T> Iterator for IterMut<'a, T> {
     type Item = &'a mut T;
 
@@ -99,13 +255,92 @@ impl<T> Iterator for ConsumerIter<T> {
 
 impl<T> Drop for LinkedStack<T> {
     fn drop(&mut self) {
-        let mut current_link = self.head.take();
-        while let Some(mut boxed_node) = current_link {
-            current_link = boxed_node.next.take();
+        self.clear();
+    }
+}
+
+impl<T> Default for LinkedStack<T> {
+    fn default() -> Self {
+        LinkedStack::new()
+    }
+}
+
+impl<T: Clone> Clone for LinkedStack<T> {
+    fn clone(&self) -> Self {
+        let items: Vec<&T> = self.iter().collect();
+
+        items.into_iter().rev().cloned().collect()
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for LinkedStack<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T: PartialEq> PartialEq for LinkedStack<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len() == other.len() && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: Eq> Eq for LinkedStack<T> {}
+
+impl<T: Hash> Hash for LinkedStack<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.len().hash(state);
+
+        for item in self.iter() {
+            item.hash(state);
         }
     }
 }
 
+impl<T> FromIterator<T> for LinkedStack<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut stack = LinkedStack::new();
+        stack.extend(iter);
+
+        stack
+    }
+}
+
+impl<T> Extend<T> for LinkedStack<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for item in iter {
+            self.push(item);
+        }
+    }
+}
+
+impl<T> IntoIterator for LinkedStack<T> {
+    type Item = T;
+    type IntoIter = ConsumerIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        ConsumerIter(self)
+    }
+}
+
+impl<'a, T> IntoIterator for &'a LinkedStack<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut LinkedStack<T> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::algorithms::linked_list_stack::LinkedStack;
@@ -199,4 +434,145 @@ mod tests {
             assert!(*v == 0);
         }
     }
+
+    #[test]
+    fn peek_len_and_clear_should_work_as_expected() {
+        let mut stack = LinkedStack::<u32>::new();
+
+        assert!(stack.len() == 0);
+        assert!(stack.peek() == None);
+        assert!(stack.peek_mut() == None);
+
+        for i in 0..10 {
+            stack.push(i);
+        }
+
+        assert!(stack.len() == 10);
+        assert!(stack.peek() == Some(&9));
+
+        *stack.peek_mut().unwrap() = 100;
+
+        assert!(stack.pop() == Some(100));
+        assert!(stack.len() == 9);
+
+        stack.clear();
+
+        assert!(stack.is_empty());
+        assert!(stack.len() == 0);
+        assert!(stack.peek() == None);
+    }
+
+    #[test]
+    fn standard_traits_should_be_implemented() {
+        use std::collections::hash_map::DefaultHasher;
+        use std::hash::{Hash, Hasher};
+
+        let mut stack: LinkedStack<u32> = (0..5).collect();
+
+        assert_eq!(format!("{:?}", stack), "[4, 3, 2, 1, 0]");
+
+        let copy = stack.clone();
+
+        assert_eq!(stack, copy);
+        assert_eq!(LinkedStack::<u32>::default(), LinkedStack::new());
+
+        let mut h0 = DefaultHasher::new();
+        let mut h1 = DefaultHasher::new();
+        stack.hash(&mut h0);
+        copy.hash(&mut h1);
+
+        assert!(h0.finish() == h1.finish());
+
+        stack.extend(vec![5, 6]);
+
+        assert!(stack != copy);
+        assert!(stack.len() == 7);
+
+        for v in &mut stack {
+            *v += 1;
+        }
+
+        let mut k = 7;
+
+        for v in &stack {
+            assert!(*v == k);
+
+            k -= 1;
+        }
+
+        let items: Vec<u32> = stack.into_iter().collect();
+
+        assert!(items == vec![7, 6, 5, 4, 3, 2, 1]);
+    }
+
+    #[test]
+    fn append_should_move_items_on_top_in_constant_time() {
+        let mut stack: LinkedStack<u32> = (0..3).collect();
+        let mut other: LinkedStack<u32> = (3..6).collect();
+
+        stack.append(&mut other);
+
+        assert!(other.is_empty());
+        assert!(stack.len() == 6);
+        assert!(stack.iter().cloned().collect::<Vec<_>>() == vec![5, 4, 3, 2, 1, 0]);
+
+        let mut empty = LinkedStack::new();
+
+        empty.append(&mut stack);
+        stack.append(&mut LinkedStack::new());
+
+        assert!(stack.is_empty());
+        assert!(empty.len() == 6);
+
+        other.push(10);
+        empty.append(&mut other);
+        other.append(&mut empty);
+
+        assert!(other.iter().cloned().collect::<Vec<_>>() == vec![10, 5, 4, 3, 2, 1, 0]);
+
+        let mut bottom: LinkedStack<u32> = (20..22).collect();
+        bottom.append(&mut other);
+
+        assert!(bottom.iter().last() == Some(&20));
+        assert!(bottom.len() == 9);
+    }
+
+    #[test]
+    fn split_off_drain_and_retain_should_work_as_expected() {
+        let mut stack: LinkedStack<u32> = (0..10).collect();
+        let mut lower = stack.split_off(4);
+
+        assert!(stack.iter().cloned().collect::<Vec<_>>() == vec![9, 8, 7, 6]);
+        assert!(lower.iter().cloned().collect::<Vec<_>>() == vec![5, 4, 3, 2, 1, 0]);
+        assert!(stack.split_off(4).is_empty());
+
+        lower.append(&mut stack);
+
+        assert!(lower.len() == 10);
+
+        let drained: Vec<u32> = lower.drain(2..5).collect();
+
+        assert!(drained == vec![7, 6, 5]);
+        assert!(lower.iter().cloned().collect::<Vec<_>>() == vec![9, 8, 4, 3, 2, 1, 0]);
+        assert!(lower.len() == 7);
+
+        lower.retain(|v| v % 2 == 0);
+
+        assert!(lower.iter().cloned().collect::<Vec<_>>() == vec![8, 4, 2, 0]);
+        assert!(lower.len() == 4);
+
+        lower.retain(|v| *v != 0);
+        lower.append(&mut (10..12).collect());
+
+        assert!(lower.iter().last() == Some(&2));
+
+        let all: Vec<u32> = lower.drain(..).collect();
+
+        assert!(all == vec![11, 10, 8, 4, 2]);
+        assert!(lower.is_empty());
+
+        lower.push(1);
+
+        assert!(lower.pop() == Some(1));
+    }
 }
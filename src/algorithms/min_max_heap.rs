@@ -0,0 +1,309 @@
+use std::iter::FromIterator;
+
+// Double-ended priority queue. Positions start at 1 like PriorityQueue, and
+// levels alternate between min levels (the root's) and max levels: every item
+// is no larger than its descendants on a min level and no smaller on a max
+// level, so the minimum is the root and the maximum one of its children.
+pub struct MinMaxHeap<T> {
+    queue: Vec<Option<T>>,
+    last: usize,
+}
+
+impl<T: Ord> MinMaxHeap<T> {
+    pub fn new() -> Self {
+        let mut queue = Vec::with_capacity(2);
+
+        queue.insert(0, None);
+
+        MinMaxHeap { queue, last: 0 }
+    }
+
+    // Bottom-up heap construction in O(n).
+    pub fn from_vec(items: Vec<T>) -> Self {
+        let last = items.len();
+        let mut queue = Vec::with_capacity(last + 1);
+
+        queue.push(None);
+        queue.extend(items.into_iter().map(Some));
+
+        let mut heap = MinMaxHeap { queue, last };
+
+        for k in (1..=last / 2).rev() {
+            heap.trickle_down(k);
+        }
+
+        heap
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.last == 0
+    }
+
+    pub fn len(&self) -> usize {
+        self.last
+    }
+
+    pub fn peek_min(&self) -> Option<&T> {
+        if self.last == 0 {
+            None
+        } else {
+            self.queue[1].as_ref()
+        }
+    }
+
+    pub fn peek_max(&self) -> Option<&T> {
+        if self.last == 0 {
+            None
+        } else {
+            self.queue[self.max_position()].as_ref()
+        }
+    }
+
+    pub fn insert(&mut self, key: T) {
+        let capacity = self.queue.capacity();
+        if self.last == capacity {
+            self.resize(2 * capacity);
+        }
+
+        self.last += 1;
+        insert(&mut self.queue, self.last, Some(key));
+
+        self.bubble_up(self.last);
+    }
+
+    pub fn delete_min(&mut self) -> Option<T> {
+        if self.last == 0 {
+            return None;
+        }
+
+        Some(self.delete_at(1))
+    }
+
+    pub fn delete_max(&mut self) -> Option<T> {
+        if self.last == 0 {
+            return None;
+        }
+
+        let k = self.max_position();
+
+        Some(self.delete_at(k))
+    }
+
+    pub fn clear(&mut self) {
+        self.queue.truncate(1);
+        self.last = 0;
+    }
+
+    fn max_position(&self) -> usize {
+        match self.last {
+            1 => 1,
+            2 => 2,
+            _ if self.item(3) > self.item(2) => 3,
+            _ => 2,
+        }
+    }
+
+    fn delete_at(&mut self, k: usize) -> T {
+        let item = self.queue[k].take();
+        self.queue.swap(k, self.last);
+        self.last -= 1;
+
+        if k <= self.last {
+            self.trickle_down(k);
+        }
+
+        let capacity = self.queue.capacity();
+        if self.last == capacity / 4 {
+            self.resize(capacity / 2);
+        }
+
+        item.unwrap()
+    }
+
+    fn bubble_up(&mut self, k: usize) {
+        if k == 1 {
+            return;
+        }
+
+        let parent = k / 2;
+        let max_level = is_max_level(k);
+
+        if self.above(k, parent, !max_level) {
+            self.queue.swap(k, parent);
+            self.bubble_up_grandparents(parent, !max_level);
+        } else {
+            self.bubble_up_grandparents(k, max_level);
+        }
+    }
+
+    fn bubble_up_grandparents(&mut self, k: usize, max_level: bool) {
+        let mut k = k;
+
+        while k >= 4 && self.above(k, k / 4, max_level) {
+            self.queue.swap(k, k / 4);
+            k /= 4;
+        }
+    }
+
+    fn trickle_down(&mut self, k: usize) {
+        let mut k = k;
+        let max_level = is_max_level(k);
+
+        while 2 * k <= self.last {
+            // The most extreme of the up to two children and four grandchildren.
+            let mut m = 2 * k;
+
+            for j in [2 * k + 1, 4 * k, 4 * k + 1, 4 * k + 2, 4 * k + 3].iter() {
+                if *j <= self.last && self.above(*j, m, max_level) {
+                    m = *j;
+                }
+            }
+
+            if !self.above(m, k, max_level) {
+                break;
+            }
+
+            self.queue.swap(m, k);
+
+            if m < 4 * k {
+                break;
+            }
+
+            if self.above(m / 2, m, max_level) {
+                self.queue.swap(m, m / 2);
+            }
+
+            k = m;
+        }
+    }
+
+    // Whether the item at `a` belongs above the one at `b` on a level of the
+    // given kind.
+    fn above(&self, a: usize, b: usize, max_level: bool) -> bool {
+        if max_level {
+            self.item(a) > self.item(b)
+        } else {
+            self.item(a) < self.item(b)
+        }
+    }
+
+    fn item(&self, k: usize) -> &T {
+        self.queue[k].as_ref().unwrap()
+    }
+
+    fn resize(&mut self, capacity: usize) {
+        let mut new_queue = Vec::with_capacity(capacity);
+
+        for i in 0..=self.last {
+            insert(&mut new_queue, i, self.queue[i].take());
+        }
+
+        self.queue = new_queue;
+    }
+}
+
+impl<T: Ord> Default for MinMaxHeap<T> {
+    fn default() -> Self {
+        MinMaxHeap::new()
+    }
+}
+
+impl<T: Ord> FromIterator<T> for MinMaxHeap<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        MinMaxHeap::from_vec(iter.into_iter().collect())
+    }
+}
+
+fn is_max_level(k: usize) -> bool {
+    (usize::BITS - 1 - k.leading_zeros()) % 2 == 1
+}
+
+fn insert<T>(v: &mut Vec<Option<T>>, i: usize, item: Option<T>) {
+    if v.len() <= i {
+        v.insert(i, item);
+    } else {
+        v[i] = item;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::algorithms::min_max_heap::MinMaxHeap;
+
+    #[test]
+    fn interface_operations_should_work_as_expected() {
+        let mut heap = MinMaxHeap::new();
+
+        assert!(heap.is_empty());
+        assert!(heap.delete_min() == None);
+        assert!(heap.delete_max() == None);
+        assert!(heap.peek_min() == None);
+        assert!(heap.peek_max() == None);
+
+        heap.insert(5);
+
+        assert!(heap.peek_min() == Some(&5));
+        assert!(heap.peek_max() == Some(&5));
+
+        for v in [1, 10, 20, 5, -10, -30, -2, 1].iter() {
+            heap.insert(*v);
+        }
+
+        assert!(heap.len() == 9);
+        assert!(heap.delete_max() == Some(20));
+        assert!(heap.delete_min() == Some(-30));
+        assert!(heap.delete_max() == Some(10));
+        assert!(heap.delete_min() == Some(-10));
+        assert!(heap.delete_max() == Some(5));
+        assert!(heap.delete_max() == Some(5));
+        assert!(heap.delete_min() == Some(-2));
+        assert!(heap.delete_min() == Some(1));
+        assert!(heap.delete_max() == Some(1));
+        assert!(heap.is_empty());
+
+        for v in 0..100 {
+            heap.insert(v);
+        }
+
+        for v in 0..50 {
+            assert!(heap.delete_max() == Some(99 - v));
+            assert!(heap.delete_min() == Some(v));
+        }
+
+        heap.insert(1);
+        heap.clear();
+
+        assert!(heap.is_empty());
+    }
+
+    #[test]
+    fn min_max_heap_should_match_a_sorted_vector() {
+        let items: Vec<i64> = (0..500).map(|i| (i * 7919) % 503 - 250).collect();
+        let mut sorted = items.clone();
+        sorted.sort();
+
+        let mut built: MinMaxHeap<i64> = items.iter().cloned().collect();
+        let mut inserted = MinMaxHeap::new();
+
+        for v in items.iter() {
+            inserted.insert(*v);
+        }
+
+        for heap in [&mut built, &mut inserted].iter_mut() {
+            let mut expected = sorted.clone();
+
+            for i in 0..500 {
+                if i % 3 == 0 {
+                    assert!(heap.delete_min() == Some(expected.remove(0)));
+                } else {
+                    assert!(heap.delete_max() == expected.pop());
+                }
+
+                assert!(heap.peek_min() == expected.first());
+                assert!(heap.peek_max() == expected.last());
+            }
+
+            assert!(heap.is_empty());
+        }
+    }
+}
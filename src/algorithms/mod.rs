@@ -1,13 +1,38 @@
+pub mod adjacency;
+pub mod arena;
+pub mod arena_queue;
+pub mod arena_stack;
 pub mod array_stack;
 pub mod binary_search_tree;
+pub mod blocking_queue;
+pub mod concurrent_stack;
 pub mod connectivity_quick_find;
 pub mod connectivity_quick_union;
 pub mod connectivity_quick_union_improved;
+pub mod dary_heap;
 pub mod directed_graph;
+pub mod dynamic_connectivity;
+pub mod expression;
+pub mod heaps;
+pub mod history;
+pub mod index_priority_queue;
+pub mod labeled_union_find;
 pub mod linked_list_queue;
 pub mod linked_list_stack;
 pub mod llrb_tree;
+pub mod min_max_heap;
+pub mod monoid_queue;
+pub mod monoid_stack;
+pub mod percolation;
+pub mod persistent_queue;
+pub mod persistent_stack;
+pub mod potential_union_find;
 pub mod priority_queue;
+pub mod rollback_union_find;
+pub mod running_median;
 pub mod separate_chainining_hash_table;
+pub mod simulation;
+pub mod small_stack;
 pub mod sorting;
 pub mod undirected_graph;
+pub mod union_find;
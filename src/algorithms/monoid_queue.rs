@@ -0,0 +1,157 @@
+use std::cmp::{max, min};
+
+use crate::algorithms::monoid_stack::MonoidStack;
+
+// Two-stack queue. Items are pushed onto the back stack and moved to the
+// front stack when it runs out, so each item is moved at most once.
+pub struct MonoidQueue<T, F> {
+    front: MonoidStack<T, F>,
+    back: MonoidStack<T, F>,
+}
+
+impl<T, F> MonoidQueue<T, F>
+where
+    T: Clone,
+    F: Fn(&T, &T) -> T + Clone,
+{
+    pub fn new(combine: F) -> Self {
+        MonoidQueue {
+            front: MonoidStack::new_reversed(combine.clone()),
+            back: MonoidStack::new(combine),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.front.is_empty() && self.back.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.front.len() + self.back.len()
+    }
+
+    pub fn enqueue(&mut self, item: T) {
+        self.back.push(item);
+    }
+
+    pub fn dequeue(&mut self) -> Option<T> {
+        if self.front.is_empty() {
+            while let Some(item) = self.back.pop() {
+                self.front.push(item);
+            }
+        }
+
+        self.front.pop()
+    }
+
+    pub fn aggregate(&self) -> Option<T> {
+        match (self.front.aggregate(), self.back.aggregate()) {
+            (None, None) => None,
+            (Some(a), None) | (None, Some(a)) => Some(a.clone()),
+            (Some(a), Some(b)) => Some(self.back.combine(a, b)),
+        }
+    }
+}
+
+impl<T> MonoidQueue<T, fn(&T, &T) -> T>
+where
+    T: Ord + Clone,
+{
+    pub fn new_min() -> Self {
+        MonoidQueue::new(|a, b| min(a, b).clone())
+    }
+
+    pub fn new_max() -> Self {
+        MonoidQueue::new(|a, b| max(a, b).clone())
+    }
+}
+
+pub fn sliding_window<T, F>(items: &[T], window: usize, combine: F) -> Vec<T>
+where
+    T: Clone,
+    F: Fn(&T, &T) -> T + Clone,
+{
+    assert!(window > 0, "window must be positive");
+
+    let mut queue = MonoidQueue::new(combine);
+    let mut aggregates = Vec::new();
+
+    for item in items {
+        queue.enqueue(item.clone());
+
+        if queue.len() > window {
+            queue.dequeue();
+        }
+
+        if queue.len() == window {
+            aggregates.push(queue.aggregate().unwrap());
+        }
+    }
+
+    aggregates
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::algorithms::monoid_queue::{sliding_window, MonoidQueue};
+
+    #[test]
+    fn interface_operations_should_work_as_expected() {
+        let mut queue = MonoidQueue::<i32, _>::new_min();
+
+        assert!(queue.is_empty());
+        assert!(queue.aggregate() == None);
+        assert!(queue.dequeue() == None);
+
+        for v in &[4, 2, 12, 3] {
+            queue.enqueue(*v);
+        }
+
+        assert!(queue.len() == 4);
+        assert!(queue.aggregate() == Some(2));
+        assert!(queue.dequeue() == Some(4));
+        assert!(queue.aggregate() == Some(2));
+        assert!(queue.dequeue() == Some(2));
+        assert!(queue.aggregate() == Some(3));
+
+        queue.enqueue(1);
+
+        assert!(queue.aggregate() == Some(1));
+        assert!(queue.dequeue() == Some(12));
+        assert!(queue.dequeue() == Some(3));
+        assert!(queue.dequeue() == Some(1));
+        assert!(queue.is_empty());
+    }
+
+    #[test]
+    fn aggregate_should_respect_queue_order_for_non_commutative_monoids() {
+        let mut queue = MonoidQueue::new(|a: &String, b: &String| format!("{}{}", a, b));
+
+        for s in &["a", "b", "c"] {
+            queue.enqueue(String::from(*s));
+        }
+
+        queue.dequeue();
+        queue.enqueue(String::from("d"));
+        queue.enqueue(String::from("e"));
+
+        assert!(queue.aggregate() == Some(String::from("bcde")));
+
+        queue.dequeue();
+
+        assert!(queue.aggregate() == Some(String::from("cde")));
+    }
+
+    #[test]
+    fn sliding_window_should_compute_window_minimums_and_maximums() {
+        let metrics = vec![1, 3, -1, -3, 5, 3, 6, 7];
+
+        let mins = sliding_window(&metrics, 3, |a: &i32, b: &i32| *a.min(b));
+        let maxs = sliding_window(&metrics, 3, |a: &i32, b: &i32| *a.max(b));
+        let sums = sliding_window(&metrics, 2, |a: &i32, b: &i32| a + b);
+
+        assert!(mins == vec![-1, -3, -3, -3, 3, 3]);
+        assert!(maxs == vec![3, 3, 5, 5, 6, 7]);
+        assert!(sums == vec![4, 2, -4, 2, 8, 9, 13]);
+        assert!(sliding_window(&metrics, 10, |a: &i32, b: &i32| a + b).is_empty());
+    }
+}
@@ -0,0 +1,171 @@
+use std::cmp::{max, min};
+
+use crate::algorithms::array_stack::{self, ArrayStack};
+
+pub struct MonoidStack<T, F> {
+    stack: ArrayStack<(T, T)>,
+    combine: F,
+    reversed: bool,
+}
+
+pub struct Iter<'a, T>(array_stack::Iter<'a, (T, T)>);
+
+impl<T, F> MonoidStack<T, F>
+where
+    T: Clone,
+    F: Fn(&T, &T) -> T,
+{
+    pub fn new(combine: F) -> Self {
+        MonoidStack {
+            stack: ArrayStack::new(),
+            combine,
+            reversed: false,
+        }
+    }
+
+    // Folds new items on the left instead of the right, so the aggregate
+    // reads the stack from top to bottom. Used for the front of MonoidQueue.
+    pub(crate) fn new_reversed(combine: F) -> Self {
+        MonoidStack {
+            stack: ArrayStack::new(),
+            combine,
+            reversed: true,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.stack.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.stack.len()
+    }
+
+    pub fn push(&mut self, item: T) {
+        let aggregate = match self.stack.peek() {
+            None => item.clone(),
+            Some((_, below)) if self.reversed => (self.combine)(&item, below),
+            Some((_, below)) => (self.combine)(below, &item),
+        };
+
+        self.stack.push((item, aggregate));
+    }
+
+    pub fn pop(&mut self) -> Option<T> {
+        self.stack.pop().map(|(item, _)| item)
+    }
+
+    pub fn peek(&self) -> Option<&T> {
+        self.stack.peek().map(|(item, _)| item)
+    }
+
+    pub fn aggregate(&self) -> Option<&T> {
+        self.stack.peek().map(|(_, aggregate)| aggregate)
+    }
+
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter(self.stack.iter())
+    }
+
+    pub(crate) fn combine(&self, a: &T, b: &T) -> T {
+        (self.combine)(a, b)
+    }
+}
+
+impl<T> MonoidStack<T, fn(&T, &T) -> T>
+where
+    T: Ord + Clone,
+{
+    pub fn new_min() -> Self {
+        MonoidStack::new(|a, b| min(a, b).clone())
+    }
+
+    pub fn new_max() -> Self {
+        MonoidStack::new(|a, b| max(a, b).clone())
+    }
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next().map(|(item, _)| item)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::algorithms::monoid_stack::MonoidStack;
+
+    #[test]
+    fn min_and_max_stacks_should_track_aggregates() {
+        let mut mins = MonoidStack::<i32, _>::new_min();
+        let mut maxs = MonoidStack::<i32, _>::new_max();
+
+        assert!(mins.aggregate() == None);
+        assert!(maxs.aggregate() == None);
+
+        for v in &[5, 3, 8, 1, 9, 2] {
+            mins.push(*v);
+            maxs.push(*v);
+        }
+
+        assert!(mins.len() == 6);
+        assert!(mins.aggregate() == Some(&1));
+        assert!(maxs.aggregate() == Some(&9));
+
+        mins.pop();
+        maxs.pop();
+
+        assert!(mins.aggregate() == Some(&1));
+        assert!(maxs.aggregate() == Some(&9));
+
+        mins.pop();
+        maxs.pop();
+
+        assert!(mins.aggregate() == Some(&1));
+        assert!(maxs.aggregate() == Some(&8));
+
+        mins.pop();
+        maxs.pop();
+
+        assert!(mins.aggregate() == Some(&3));
+        assert!(maxs.aggregate() == Some(&8));
+        assert!(mins.peek() == Some(&8));
+        assert!(mins.iter().cloned().collect::<Vec<_>>() == vec![8, 3, 5]);
+    }
+
+    #[test]
+    fn arbitrary_monoids_should_be_supported() {
+        fn gcd(a: &u64, b: &u64) -> u64 {
+            if *b == 0 {
+                *a
+            } else {
+                gcd(b, &(a % b))
+            }
+        }
+
+        let mut gcds = MonoidStack::new(gcd);
+
+        gcds.push(84);
+        gcds.push(36);
+
+        assert!(gcds.aggregate() == Some(&12));
+
+        gcds.push(10);
+
+        assert!(gcds.aggregate() == Some(&2));
+
+        gcds.pop();
+
+        assert!(gcds.aggregate() == Some(&12));
+
+        let mut concat = MonoidStack::new(|a: &String, b: &String| format!("{}{}", a, b));
+
+        concat.push(String::from("a"));
+        concat.push(String::from("b"));
+        concat.push(String::from("c"));
+
+        assert!(concat.aggregate() == Some(&String::from("abc")));
+    }
+}
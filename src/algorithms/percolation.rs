@@ -0,0 +1,242 @@
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+use crate::algorithms::connectivity_quick_union_improved::Network;
+
+// An n-by-n grid of sites, all blocked at first. A site is full when an open
+// path leads to it from the top row, and the grid percolates when the bottom
+// row is reached. Virtual sites above the top row and below the bottom row
+// make both checks a single connectivity query; `full` has no bottom site, so
+// that open sites joined only through the bottom row do not look full.
+pub struct Percolation {
+    n: usize,
+    open: Vec<bool>,
+    num_open: usize,
+    sites: Network,
+    full: Network,
+}
+
+// Monte Carlo estimate of the percolation threshold: every trial opens random
+// sites of a fresh grid until it percolates and records the fraction opened.
+pub struct PercolationStats {
+    thresholds: Vec<f64>,
+}
+
+impl Percolation {
+    pub fn new(n: usize) -> Self {
+        assert!(n > 0, "grid must not be empty");
+
+        Percolation {
+            n,
+            open: vec![false; n * n],
+            num_open: 0,
+            sites: Network::new(n * n + 2),
+            full: Network::new(n * n + 1),
+        }
+    }
+
+    pub fn size(&self) -> usize {
+        self.n
+    }
+
+    pub fn num_open(&self) -> usize {
+        self.num_open
+    }
+
+    pub fn open(&mut self, row: usize, col: usize) {
+        let site = self.site(row, col);
+
+        if self.open[site] {
+            return;
+        }
+
+        self.open[site] = true;
+        self.num_open += 1;
+
+        if row == 0 {
+            self.sites.union(site, self.top());
+            self.full.union(site, self.top());
+        }
+
+        if row == self.n - 1 {
+            self.sites.union(site, self.bottom());
+        }
+
+        let neighbours = [
+            (row.wrapping_sub(1), col),
+            (row + 1, col),
+            (row, col.wrapping_sub(1)),
+            (row, col + 1),
+        ];
+
+        for &(r, c) in neighbours.iter() {
+            if r < self.n && c < self.n && self.is_open(r, c) {
+                let other = self.site(r, c);
+
+                self.sites.union(site, other);
+                self.full.union(site, other);
+            }
+        }
+    }
+
+    pub fn is_open(&self, row: usize, col: usize) -> bool {
+        self.open[self.site(row, col)]
+    }
+
+    pub fn is_full(&self, row: usize, col: usize) -> bool {
+        self.is_open(row, col) && self.full.connected(self.site(row, col), self.top())
+    }
+
+    pub fn percolates(&self) -> bool {
+        self.sites.connected(self.top(), self.bottom())
+    }
+
+    fn site(&self, row: usize, col: usize) -> usize {
+        assert!(row < self.n && col < self.n, "site out of range");
+
+        row * self.n + col
+    }
+
+    fn top(&self) -> usize {
+        self.n * self.n
+    }
+
+    fn bottom(&self) -> usize {
+        self.n * self.n + 1
+    }
+}
+
+impl PercolationStats {
+    pub fn run<R: Rng>(n: usize, trials: usize, rng: &mut R) -> Self {
+        assert!(trials > 1, "confidence intervals need at least two trials");
+
+        let mut order: Vec<usize> = (0..n * n).collect();
+        let mut thresholds = Vec::with_capacity(trials);
+
+        for _ in 0..trials {
+            let mut grid = Percolation::new(n);
+
+            order.shuffle(rng);
+
+            for &site in order.iter() {
+                grid.open(site / n, site % n);
+
+                if grid.percolates() {
+                    break;
+                }
+            }
+
+            thresholds.push(grid.num_open() as f64 / (n * n) as f64);
+        }
+
+        PercolationStats { thresholds }
+    }
+
+    pub fn thresholds(&self) -> &[f64] {
+        &self.thresholds
+    }
+
+    pub fn mean(&self) -> f64 {
+        self.thresholds.iter().sum::<f64>() / self.thresholds.len() as f64
+    }
+
+    // Sample standard deviation.
+    pub fn stddev(&self) -> f64 {
+        let mean = self.mean();
+        let squares: f64 = self
+            .thresholds
+            .iter()
+            .map(|t| (t - mean) * (t - mean))
+            .sum();
+
+        (squares / (self.thresholds.len() - 1) as f64).sqrt()
+    }
+
+    // 95% confidence interval for the threshold.
+    pub fn confidence_interval(&self) -> (f64, f64) {
+        let mean = self.mean();
+        let margin = 1.96 * self.stddev() / (self.thresholds.len() as f64).sqrt();
+
+        (mean - margin, mean + margin)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    use crate::algorithms::percolation::{Percolation, PercolationStats};
+
+    #[test]
+    fn grid_should_percolate_once_a_path_connects_top_and_bottom() {
+        let mut grid = Percolation::new(3);
+
+        assert!(!grid.percolates());
+        assert!(!grid.is_open(0, 0));
+        assert!(!grid.is_full(0, 0));
+
+        grid.open(0, 1);
+        grid.open(0, 1);
+        grid.open(1, 1);
+
+        assert!(grid.num_open() == 2);
+        assert!(grid.is_full(1, 1));
+        assert!(!grid.percolates());
+
+        grid.open(2, 0);
+
+        assert!(grid.is_open(2, 0));
+        assert!(!grid.is_full(2, 0));
+
+        grid.open(2, 1);
+
+        assert!(grid.percolates());
+        assert!(grid.is_full(2, 0));
+        assert!(!grid.is_full(0, 0));
+    }
+
+    #[test]
+    fn sites_joined_only_through_the_bottom_row_should_not_be_full() {
+        let mut grid = Percolation::new(3);
+
+        for row in 0..3 {
+            grid.open(row, 0);
+        }
+
+        grid.open(2, 2);
+        grid.open(1, 2);
+
+        assert!(grid.percolates());
+        assert!(!grid.is_full(2, 2));
+        assert!(!grid.is_full(1, 2));
+
+        let mut single = Percolation::new(1);
+        single.open(0, 0);
+
+        assert!(single.percolates());
+        assert!(single.is_full(0, 0));
+    }
+
+    #[test]
+    #[should_panic]
+    fn opening_a_site_outside_the_grid_should_panic() {
+        Percolation::new(4).open(4, 0);
+    }
+
+    #[test]
+    fn estimated_threshold_should_be_close_to_the_known_value() {
+        let mut rng = StdRng::seed_from_u64(49);
+        let stats = PercolationStats::run(40, 100, &mut rng);
+        let (low, high) = stats.confidence_interval();
+
+        assert!(stats.thresholds().len() == 100);
+        assert!(low < stats.mean() && stats.mean() < high);
+        assert!((stats.mean() - 0.5927).abs() < 0.02);
+        assert!(stats.stddev() > 0.0 && stats.stddev() < 0.1);
+
+        let again = PercolationStats::run(40, 100, &mut StdRng::seed_from_u64(49));
+
+        assert!(again.thresholds() == stats.thresholds());
+    }
+}
@@ -0,0 +1,404 @@
+use std::cell::{OnceCell, RefCell};
+use std::fmt;
+use std::iter::FromIterator;
+use std::rc::Rc;
+
+use crate::algorithms::persistent_stack::PersistentStack;
+
+// Okasaki's real-time queue. The front is a lazy stream whose suspensions
+// are memoized, so every version shares the work done by the others, and the
+// schedule forces one suspension per operation, giving O(1) worst case.
+pub struct PersistentQueue<T> {
+    front: Stream<T>,
+    rear: PersistentStack<Rc<T>>,
+    schedule: Stream<T>,
+    len: usize,
+}
+
+type Stream<T> = Rc<StreamNode<T>>;
+
+struct StreamNode<T> {
+    value: OnceCell<Cell<T>>,
+    pending: RefCell<Option<Rotation<T>>>,
+}
+
+enum Cell<T> {
+    Nil,
+    Cons(Rc<T>, Stream<T>),
+}
+
+struct Rotation<T> {
+    front: Stream<T>,
+    rear: PersistentStack<Rc<T>>,
+    acc: Stream<T>,
+}
+
+pub struct IntoIter<T>(PersistentQueue<T>);
+
+pub struct Iter<'a, T> {
+    front: Option<&'a StreamNode<T>>,
+    rear: Vec<&'a T>,
+}
+
+impl<T> PersistentQueue<T> {
+    pub fn new() -> Self {
+        let empty = forced(Cell::Nil);
+
+        PersistentQueue {
+            front: empty.clone(),
+            rear: PersistentStack::new(),
+            schedule: empty,
+            len: 0,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn enqueue(&self, item: T) -> Self {
+        exec(
+            self.front.clone(),
+            self.rear.push(Rc::new(item)),
+            &self.schedule,
+            self.len + 1,
+        )
+    }
+
+    pub fn dequeue(&self) -> Option<(&T, Self)> {
+        match force(&self.front) {
+            Cell::Nil => None,
+            Cell::Cons(item, rest) => {
+                let queue = exec(
+                    rest.clone(),
+                    self.rear.clone(),
+                    &self.schedule,
+                    self.len - 1,
+                );
+
+                Some((&**item, queue))
+            }
+        }
+    }
+
+    pub fn peek(&self) -> Option<&T> {
+        match force(&self.front) {
+            Cell::Nil => None,
+            Cell::Cons(item, _) => Some(&**item),
+        }
+    }
+
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            front: Some(&*self.front),
+            rear: self.rear.iter().map(|item| &**item).collect(),
+        }
+    }
+}
+
+fn forced<T>(cell: Cell<T>) -> Stream<T> {
+    let value = OnceCell::new();
+    let _ = value.set(cell);
+
+    Rc::new(StreamNode {
+        value,
+        pending: RefCell::new(None),
+    })
+}
+
+fn delayed<T>(rotation: Rotation<T>) -> Stream<T> {
+    Rc::new(StreamNode {
+        value: OnceCell::new(),
+        pending: RefCell::new(Some(rotation)),
+    })
+}
+
+fn force<T>(stream: &StreamNode<T>) -> &Cell<T> {
+    if let Some(cell) = stream.value.get() {
+        return cell;
+    }
+
+    let rotation = stream.pending.borrow_mut().take().unwrap();
+
+    stream.value.get_or_init(|| rotation.run())
+}
+
+// Forces the next scheduled suspension, or starts a new rotation once the
+// schedule runs out, which happens exactly when the rear outgrows the front.
+fn exec<T>(
+    front: Stream<T>,
+    rear: PersistentStack<Rc<T>>,
+    schedule: &Stream<T>,
+    len: usize,
+) -> PersistentQueue<T> {
+    match force(schedule) {
+        Cell::Cons(_, rest) => PersistentQueue {
+            front,
+            rear,
+            schedule: rest.clone(),
+            len,
+        },
+        Cell::Nil => {
+            let front = delayed(Rotation {
+                front,
+                rear,
+                acc: forced(Cell::Nil),
+            });
+
+            PersistentQueue {
+                front: front.clone(),
+                rear: PersistentStack::new(),
+                schedule: front,
+                len,
+            }
+        }
+    }
+}
+
+impl<T> Rotation<T> {
+    fn run(self) -> Cell<T> {
+        let (last, rear) = self.rear.pop().unwrap();
+
+        match force(&self.front) {
+            Cell::Nil => Cell::Cons(last.clone(), self.acc),
+            Cell::Cons(item, rest) => Cell::Cons(
+                item.clone(),
+                delayed(Rotation {
+                    front: rest.clone(),
+                    rear,
+                    acc: forced(Cell::Cons(last.clone(), self.acc)),
+                }),
+            ),
+        }
+    }
+}
+
+impl<T> Drop for StreamNode<T> {
+    fn drop(&mut self) {
+        let mut next = match self.value.take() {
+            Some(Cell::Cons(_, next)) => next,
+            _ => return,
+        };
+
+        while let Ok(mut node) = Rc::try_unwrap(next) {
+            match node.value.take() {
+                Some(Cell::Cons(_, rest)) => next = rest,
+                _ => break,
+            }
+        }
+    }
+}
+
+impl<T> Clone for PersistentQueue<T> {
+    fn clone(&self) -> Self {
+        PersistentQueue {
+            front: self.front.clone(),
+            rear: self.rear.clone(),
+            schedule: self.schedule.clone(),
+            len: self.len,
+        }
+    }
+}
+
+impl<T> Default for PersistentQueue<T> {
+    fn default() -> Self {
+        PersistentQueue::new()
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for PersistentQueue<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T: PartialEq> PartialEq for PersistentQueue<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len() == other.len() && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: Eq> Eq for PersistentQueue<T> {}
+
+impl<T> FromIterator<T> for PersistentQueue<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut queue = PersistentQueue::new();
+
+        for item in iter {
+            queue = queue.enqueue(item);
+        }
+
+        queue
+    }
+}
+
+impl<T: Clone> IntoIterator for PersistentQueue<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter(self)
+    }
+}
+
+impl<'a, T> IntoIterator for &'a PersistentQueue<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<T: Clone> Iterator for IntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (item, rest) = match self.0.dequeue() {
+            None => return None,
+            Some((item, rest)) => (item.clone(), rest),
+        };
+
+        self.0 = rest;
+
+        Some(item)
+    }
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(node) = self.front {
+            match force(node) {
+                Cell::Cons(item, rest) => {
+                    self.front = Some(&**rest);
+                    return Some(&**item);
+                }
+                Cell::Nil => self.front = None,
+            }
+        }
+
+        self.rear.pop()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::algorithms::persistent_queue::PersistentQueue;
+
+    #[test]
+    fn interface_operations_should_work_as_expected() {
+        let mut queue = PersistentQueue::new();
+
+        assert!(queue.is_empty());
+        assert!(queue.dequeue().is_none());
+        assert!(queue.peek() == None);
+
+        for i in 0..1000 {
+            queue = queue.enqueue(i);
+        }
+
+        assert!(queue.len() == 1000);
+        assert!(queue.peek() == Some(&0));
+
+        for i in 0..1000 {
+            let (item, rest) = queue.dequeue().unwrap();
+
+            assert!(*item == i);
+
+            queue = rest;
+        }
+
+        assert!(queue.is_empty());
+
+        for i in 0..50 {
+            queue = queue.enqueue(i).enqueue(i + 1).dequeue().unwrap().1;
+        }
+
+        assert!(queue.len() == 50);
+    }
+
+    #[test]
+    fn old_versions_should_remain_valid_after_updates() {
+        let base: PersistentQueue<u32> = (0..5).collect();
+        let (_, tail) = base.dequeue().unwrap();
+        let branch_a = tail.enqueue(10);
+        let branch_b = tail.enqueue(20).enqueue(30);
+
+        assert!(base.iter().cloned().collect::<Vec<_>>() == vec![0, 1, 2, 3, 4]);
+        assert!(tail.iter().cloned().collect::<Vec<_>>() == vec![1, 2, 3, 4]);
+        assert!(branch_a.iter().cloned().collect::<Vec<_>>() == vec![1, 2, 3, 4, 10]);
+        assert!(branch_b.iter().cloned().collect::<Vec<_>>() == vec![1, 2, 3, 4, 20, 30]);
+
+        drop(base);
+        drop(tail);
+
+        assert_eq!(format!("{:?}", branch_a), "[1, 2, 3, 4, 10]");
+        assert!(branch_b.clone() == branch_b);
+    }
+
+    #[test]
+    fn versions_should_agree_with_an_ephemeral_queue() {
+        use std::collections::VecDeque;
+
+        let mut versions = vec![(PersistentQueue::new(), VecDeque::new())];
+
+        for i in 0..2000usize {
+            let (queue, model) = versions[(i * 7919) % versions.len()].clone();
+
+            let next = if i % 3 == 2 {
+                match queue.dequeue() {
+                    None => (queue, model),
+                    Some((item, rest)) => {
+                        let mut model = model;
+
+                        assert!(model.pop_front() == Some(*item));
+
+                        (rest, model)
+                    }
+                }
+            } else {
+                let mut model = model;
+                model.push_back(i);
+
+                (queue.enqueue(i), model)
+            };
+
+            assert!(next.0.len() == next.1.len());
+            assert!(next.0.iter().eq(next.1.iter()));
+
+            versions.push(next);
+        }
+    }
+
+    #[test]
+    fn into_iter_should_consume_the_queue() {
+        let queue: PersistentQueue<u32> = (0..25).collect();
+
+        let mut k = 0;
+
+        for v in queue {
+            assert!(v == k);
+
+            k += 1;
+        }
+
+        assert!(k == 25);
+    }
+
+    #[test]
+    fn dropping_long_queues_should_not_overflow() {
+        let queue: PersistentQueue<u32> = (0..1_000_000).collect();
+        let (_, shared) = queue.dequeue().unwrap();
+
+        drop(queue);
+
+        assert!(shared.len() == 999_999);
+        assert!(shared.peek() == Some(&1));
+    }
+}
@@ -0,0 +1,243 @@
+use std::fmt;
+use std::iter::FromIterator;
+use std::rc::Rc;
+
+pub struct PersistentStack<T> {
+    head: Link<T>,
+    len: usize,
+}
+
+type Link<T> = Option<Rc<Node<T>>>;
+
+struct Node<T> {
+    item: T,
+    next: Link<T>,
+}
+
+pub struct IntoIter<T>(PersistentStack<T>);
+
+pub struct Iter<'a, T> {
+    next: Option<&'a Node<T>>,
+}
+
+impl<T> PersistentStack<T> {
+    pub fn new() -> Self {
+        PersistentStack { head: None, len: 0 }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.head.is_none()
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn push(&self, item: T) -> Self {
+        let new_node = Rc::new(Node {
+            item,
+            next: self.head.clone(),
+        });
+
+        PersistentStack {
+            head: Some(new_node),
+            len: self.len + 1,
+        }
+    }
+
+    pub fn pop(&self) -> Option<(&T, Self)> {
+        self.head.as_ref().map(|node| {
+            let rest = PersistentStack {
+                head: node.next.clone(),
+                len: self.len - 1,
+            };
+
+            (&node.item, rest)
+        })
+    }
+
+    pub fn peek(&self) -> Option<&T> {
+        self.head.as_ref().map(|node| &node.item)
+    }
+
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            next: self.head.as_deref(),
+        }
+    }
+}
+
+impl<T> Drop for PersistentStack<T> {
+    fn drop(&mut self) {
+        let mut current_link = self.head.take();
+        while let Some(node) = current_link {
+            match Rc::try_unwrap(node) {
+                Ok(mut node) => current_link = node.next.take(),
+                Err(_) => break,
+            }
+        }
+    }
+}
+
+impl<T> Clone for PersistentStack<T> {
+    fn clone(&self) -> Self {
+        PersistentStack {
+            head: self.head.clone(),
+            len: self.len,
+        }
+    }
+}
+
+impl<T> Default for PersistentStack<T> {
+    fn default() -> Self {
+        PersistentStack::new()
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for PersistentStack<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T: PartialEq> PartialEq for PersistentStack<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len() == other.len() && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: Eq> Eq for PersistentStack<T> {}
+
+impl<T> FromIterator<T> for PersistentStack<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut stack = PersistentStack::new();
+
+        for item in iter {
+            stack = stack.push(item);
+        }
+
+        stack
+    }
+}
+
+impl<T: Clone> IntoIterator for PersistentStack<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter(self)
+    }
+}
+
+impl<'a, T> IntoIterator for &'a PersistentStack<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<T: Clone> Iterator for IntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (item, rest) = match self.0.pop() {
+            None => return None,
+            Some((item, rest)) => (item.clone(), rest),
+        };
+
+        self.0 = rest;
+
+        Some(item)
+    }
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next.map(|node| {
+            self.next = node.next.as_deref();
+            &node.item
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::algorithms::persistent_stack::PersistentStack;
+
+    #[test]
+    fn interface_operations_should_work_as_expected() {
+        let empty = PersistentStack::<u32>::new();
+
+        assert!(empty.is_empty());
+        assert!(empty.pop().is_none());
+        assert!(empty.peek() == None);
+
+        let s1 = empty.push(1);
+        let s2 = s1.push(2);
+        let s3 = s2.push(3);
+
+        assert!(s3.len() == 3);
+        assert!(s3.peek() == Some(&3));
+
+        let (top, rest) = s3.pop().unwrap();
+
+        assert!(*top == 3);
+        assert!(rest == s2);
+
+        let (top, rest) = rest.pop().unwrap();
+
+        assert!(*top == 2);
+        assert!(rest == s1);
+
+        let (top, rest) = rest.pop().unwrap();
+
+        assert!(*top == 1);
+        assert!(rest.is_empty());
+    }
+
+    #[test]
+    fn old_versions_should_remain_valid_after_updates() {
+        let base: PersistentStack<u32> = (0..5).collect();
+        let branch_a = base.push(10);
+        let branch_b = base.pop().unwrap().1.push(20);
+
+        assert!(base.iter().cloned().collect::<Vec<_>>() == vec![4, 3, 2, 1, 0]);
+        assert!(branch_a.iter().cloned().collect::<Vec<_>>() == vec![10, 4, 3, 2, 1, 0]);
+        assert!(branch_b.iter().cloned().collect::<Vec<_>>() == vec![20, 3, 2, 1, 0]);
+
+        drop(base);
+
+        assert!(branch_a.len() == 6);
+        assert!(branch_b.len() == 5);
+        assert_eq!(format!("{:?}", branch_b), "[20, 3, 2, 1, 0]");
+    }
+
+    #[test]
+    fn into_iter_should_consume_the_stack() {
+        let stack: PersistentStack<u32> = (0..25).collect();
+
+        let mut k = 25;
+
+        for v in stack {
+            k -= 1;
+
+            assert!(v == k);
+        }
+
+        assert!(k == 0);
+    }
+
+    #[test]
+    fn dropping_long_stacks_should_not_overflow() {
+        let stack: PersistentStack<u32> = (0..1_000_000).collect();
+        let shared = stack.pop().unwrap().1;
+
+        drop(stack);
+
+        assert!(shared.len() == 999_999);
+    }
+}
@@ -0,0 +1,290 @@
+use std::cell::Cell;
+
+use crate::algorithms::connectivity_quick_union_improved::halve_path;
+
+// The values that relate two elements. The operation needs to be associative
+// with an identity and inverses, but not necessarily commutative.
+pub trait Group: Copy + PartialEq {
+    fn identity() -> Self;
+
+    fn combine(self, other: Self) -> Self;
+
+    fn inverse(self) -> Self;
+}
+
+macro_rules! additive_group {
+    ($($t:ty),*) => {
+        $(
+            impl Group for $t {
+                fn identity() -> Self {
+                    0
+                }
+
+                fn combine(self, other: Self) -> Self {
+                    self + other
+                }
+
+                fn inverse(self) -> Self {
+                    -self
+                }
+            }
+        )*
+    };
+}
+
+additive_group!(i32, i64, isize);
+
+// Booleans under exclusive or, for parity constraints.
+impl Group for bool {
+    fn identity() -> Self {
+        false
+    }
+
+    fn combine(self, other: Self) -> Self {
+        self ^ other
+    }
+
+    fn inverse(self) -> Self {
+        self
+    }
+}
+
+// Weighted quick-union with path halving where every element also stores its
+// potential relative to its parent: value(p) = potentials[p] * value(parent).
+// Halving a path combines the potentials it skips over, so after `root` an
+// element's potential is relative to its new parent.
+pub struct PotentialUnionFind<G> {
+    tree: Vec<Cell<usize>>,
+    potentials: Vec<Cell<G>>,
+    weights: Vec<usize>,
+    count: usize,
+}
+
+// `union_with` was asked for a difference other than the one already implied
+// by earlier unions, which is carried here.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Contradiction<G>(pub G);
+
+impl<G: Group> PotentialUnionFind<G> {
+    pub fn new(num_elts: usize) -> Self {
+        PotentialUnionFind {
+            tree: (0..num_elts).map(Cell::new).collect(),
+            potentials: (0..num_elts).map(|_| Cell::new(G::identity())).collect(),
+            weights: vec![1; num_elts],
+            count: num_elts,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.tree.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.tree.len()
+    }
+
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    pub fn find(&self, p: usize) -> usize {
+        self.root(p).0
+    }
+
+    pub fn connected(&self, p: usize, q: usize) -> bool {
+        self.find(p) == self.find(q)
+    }
+
+    // value(p) * value(q)^-1, if p and q are connected.
+    pub fn diff(&self, p: usize, q: usize) -> Option<G> {
+        let (root_p, a) = self.root(p);
+        let (root_q, b) = self.root(q);
+
+        if root_p == root_q {
+            Some(a.combine(b.inverse()))
+        } else {
+            None
+        }
+    }
+
+    // Records that value(p) * value(q)^-1 == diff. Fails without changing
+    // anything if p and q are already connected by a different difference.
+    pub fn union_with(&mut self, p: usize, q: usize, diff: G) -> Result<(), Contradiction<G>> {
+        let (root_p, a) = self.root(p);
+        let (root_q, b) = self.root(q);
+
+        if root_p == root_q {
+            let known = a.combine(b.inverse());
+
+            return if known == diff {
+                Ok(())
+            } else {
+                Err(Contradiction(known))
+            };
+        }
+
+        if self.weights[root_p] < self.weights[root_q] {
+            let potential = a.inverse().combine(diff).combine(b);
+
+            self.tree[root_p].set(root_q);
+            self.potentials[root_p].set(potential);
+            self.weights[root_q] += self.weights[root_p];
+        } else {
+            let potential = b.inverse().combine(diff.inverse()).combine(a);
+
+            self.tree[root_q].set(root_p);
+            self.potentials[root_q].set(potential);
+            self.weights[root_p] += self.weights[root_q];
+        }
+
+        self.count -= 1;
+
+        Ok(())
+    }
+
+    // Returns the root together with value(node) * value(root)^-1.
+    fn root(&self, node: usize) -> (usize, G) {
+        let mut potential = G::identity();
+
+        let root = halve_path(&self.tree, node, |current_node, parent| {
+            let skipped = self.potentials[current_node]
+                .get()
+                .combine(self.potentials[parent].get());
+
+            self.potentials[current_node].set(skipped);
+            potential = potential.combine(skipped);
+        });
+
+        (root, potential)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    use crate::algorithms::potential_union_find::{Contradiction, Group, PotentialUnionFind};
+
+    #[test]
+    fn differences_should_follow_from_earlier_unions() {
+        let mut set = PotentialUnionFind::<i64>::new(5);
+
+        assert!(set.diff(0, 0) == Some(0));
+        assert!(set.diff(0, 1) == None);
+
+        assert!(set.union_with(1, 0, 3) == Ok(()));
+        assert!(set.union_with(2, 1, 4) == Ok(()));
+        assert!(set.union_with(3, 4, -2) == Ok(()));
+
+        assert!(set.count() == 2);
+        assert!(set.diff(2, 0) == Some(7));
+        assert!(set.diff(0, 2) == Some(-7));
+        assert!(set.diff(0, 3) == None);
+
+        assert!(set.union_with(2, 0, 7) == Ok(()));
+        assert!(set.union_with(2, 0, 6) == Err(Contradiction(7)));
+        assert!(set.count() == 2);
+
+        assert!(set.union_with(4, 2, 10) == Ok(()));
+
+        assert!(set.count() == 1);
+        assert!(set.diff(3, 0) == Some(15));
+        assert!(set.connected(3, 1));
+    }
+
+    #[test]
+    fn parity_constraints_should_detect_odd_cycles() {
+        let mut set = PotentialUnionFind::<bool>::new(6);
+
+        // Edges of a 4-cycle and a triangle: neighbours have opposite parity.
+        for (p, q) in [(0, 1), (1, 2), (2, 3), (3, 0)].iter() {
+            assert!(set.union_with(*p, *q, true) == Ok(()));
+        }
+
+        assert!(set.diff(0, 2) == Some(false));
+        assert!(set.union_with(3, 5, true) == Ok(()));
+        assert!(set.union_with(5, 4, true) == Ok(()));
+        assert!(set.union_with(4, 3, true) == Err(Contradiction(false)));
+    }
+
+    #[test]
+    fn differences_should_match_hidden_values_after_random_unions() {
+        let n = 300;
+        let mut rng = StdRng::seed_from_u64(50);
+        let values: Vec<i64> = (0..n).map(|_| rng.gen_range(-1000, 1000)).collect();
+        let mut set = PotentialUnionFind::new(n);
+
+        for _ in 0..600 {
+            let p = rng.gen_range(0, n);
+            let q = rng.gen_range(0, n);
+
+            assert!(set.union_with(p, q, values[p] - values[q]) == Ok(()));
+
+            let (a, b) = (rng.gen_range(0, n), rng.gen_range(0, n));
+
+            match set.diff(a, b) {
+                Some(d) => assert!(d == values[a] - values[b]),
+                None => assert!(!set.connected(a, b)),
+            }
+        }
+
+        assert!(set.union_with(0, 1, values[0] - values[1] + 1).is_err());
+    }
+
+    // Permutations of three elements, the smallest non-commutative group.
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    struct Perm([usize; 3]);
+
+    impl Group for Perm {
+        fn identity() -> Self {
+            Perm([0, 1, 2])
+        }
+
+        fn combine(self, other: Self) -> Self {
+            Perm([other.0[self.0[0]], other.0[self.0[1]], other.0[self.0[2]]])
+        }
+
+        fn inverse(self) -> Self {
+            let mut inverse = [0; 3];
+
+            for (i, &j) in self.0.iter().enumerate() {
+                inverse[j] = i;
+            }
+
+            Perm(inverse)
+        }
+    }
+
+    #[test]
+    fn non_commutative_groups_should_keep_the_order_of_differences() {
+        let perms = [
+            Perm([0, 1, 2]),
+            Perm([1, 0, 2]),
+            Perm([0, 2, 1]),
+            Perm([2, 1, 0]),
+            Perm([1, 2, 0]),
+            Perm([2, 0, 1]),
+        ];
+
+        let n = 100;
+        let mut rng = StdRng::seed_from_u64(51);
+        let values: Vec<Perm> = (0..n).map(|_| perms[rng.gen_range(0, 6)]).collect();
+        let relation = |p: usize, q: usize| values[p].combine(values[q].inverse());
+        let mut set = PotentialUnionFind::new(n);
+
+        for _ in 0..200 {
+            let (p, q) = (rng.gen_range(0, n), rng.gen_range(0, n));
+
+            assert!(set.union_with(p, q, relation(p, q)) == Ok(()));
+        }
+
+        for p in 0..n {
+            for q in 0..n {
+                if set.connected(p, q) {
+                    assert!(set.diff(p, q) == Some(relation(p, q)));
+                }
+            }
+        }
+    }
+}
@@ -1,14 +1,54 @@
-pub struct PriorityQueue<T> {
-    queue: Vec<Option<T>>,
+use std::cmp::Ordering;
+use std::iter::FromIterator;
+
+// The item that compares greatest under `comparator` is deleted first. Every
+// item is stored with its insertion sequence number, which breaks ties between
+// equal items in stable mode so they come out first in, first out. A queue
+// that never becomes stable still pays a u64 per item for it: the numbers are
+// tracked from the start so that `stable` can order the items inserted before
+// it was called.
+pub struct PriorityQueue<T, F = fn(&T, &T) -> Ordering> {
+    queue: Vec<Option<(T, u64)>>,
     last: usize,
-    is_max: bool,
+    comparator: F,
+    sequence: u64,
+    stable: bool,
 }
 
-impl<T> PriorityQueue<T>
-where
-    T: Ord,
-{
+// Deletes items in priority order. Whatever is left when it is dropped is
+// discarded.
+pub struct Drain<'a, T, F>(&'a mut PriorityQueue<T, F>);
+
+impl<T: Ord> PriorityQueue<T> {
     pub fn new(is_max: bool) -> Self {
+        if is_max {
+            PriorityQueue::new_by(|a: &T, b: &T| a.cmp(b))
+        } else {
+            PriorityQueue::new_by(|a: &T, b: &T| b.cmp(a))
+        }
+    }
+
+    pub fn from_vec(items: Vec<T>, is_max: bool) -> Self {
+        if is_max {
+            PriorityQueue::from_vec_by(items, |a: &T, b: &T| a.cmp(b))
+        } else {
+            PriorityQueue::from_vec_by(items, |a: &T, b: &T| b.cmp(a))
+        }
+    }
+}
+
+// Collects into a max priority queue, like std's BinaryHeap.
+impl<T: Ord> FromIterator<T> for PriorityQueue<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        PriorityQueue::from_vec(iter.into_iter().collect(), true)
+    }
+}
+
+impl<T> PriorityQueue<T> {
+    pub fn new_by<F>(comparator: F) -> PriorityQueue<T, F>
+    where
+        F: Fn(&T, &T) -> Ordering,
+    {
         let mut queue = Vec::with_capacity(2);
 
         queue.insert(0, None);
@@ -16,14 +56,118 @@ where
         PriorityQueue {
             queue,
             last: 0,
-            is_max,
+            comparator,
+            sequence: 0,
+            stable: false,
         }
     }
 
+    pub fn new_by_key<K, G>(key: G) -> PriorityQueue<T, impl Fn(&T, &T) -> Ordering>
+    where
+        K: Ord,
+        G: Fn(&T) -> K,
+    {
+        PriorityQueue::new_by(move |a: &T, b: &T| key(a).cmp(&key(b)))
+    }
+
+    // Bottom-up heap construction in O(n).
+    pub fn from_vec_by<F>(items: Vec<T>, comparator: F) -> PriorityQueue<T, F>
+    where
+        F: Fn(&T, &T) -> Ordering,
+    {
+        let last = items.len();
+        let mut queue = Vec::with_capacity(last + 1);
+
+        queue.push(None);
+        queue.extend(items.into_iter().zip(0..).map(Some));
+
+        let mut pq = PriorityQueue {
+            queue,
+            last,
+            comparator,
+            sequence: last as u64,
+            stable: false,
+        };
+
+        pq.heapify();
+
+        pq
+    }
+}
+
+impl<T, F> PriorityQueue<T, F>
+where
+    F: Fn(&T, &T) -> Ordering,
+{
+    // Switches to stable mode, where equal items are deleted in the order
+    // they were inserted. Items already in the queue keep their order.
+    pub fn stable(mut self) -> Self {
+        self.stable = true;
+        self.heapify();
+
+        self
+    }
+
+    pub fn is_stable(&self) -> bool {
+        self.stable
+    }
+
     pub fn is_empty(&self) -> bool {
         self.last == 0
     }
 
+    pub fn len(&self) -> usize {
+        self.last
+    }
+
+    pub fn peek(&self) -> Option<&T> {
+        if self.last == 0 {
+            None
+        } else {
+            self.queue[1].as_ref().map(|(item, _)| item)
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.queue.truncate(1);
+        self.last = 0;
+    }
+
+    // Inserts every item of `other`, so it takes O(m log(n + m)).
+    pub fn meld(&mut self, other: Self) {
+        let mut items: Vec<_> = other.queue.into_iter().skip(1).take(other.last).collect();
+
+        // Keeps equal items of `other` in their relative order.
+        items.sort_by_key(|item| item.as_ref().unwrap().1);
+
+        for item in items {
+            self.insert(item.unwrap().0);
+        }
+    }
+
+    pub fn drain(&mut self) -> Drain<'_, T, F> {
+        Drain(self)
+    }
+
+    // Ascending under the comparator, so the item that would be deleted first
+    // ends up last, like BinaryHeap::into_sorted_vec.
+    pub fn into_sorted_vec(mut self) -> Vec<T> {
+        let len = self.last;
+
+        while self.last > 1 {
+            self.queue.swap(1, self.last);
+            self.last -= 1;
+            self.sink(1);
+        }
+
+        self.queue
+            .into_iter()
+            .skip(1)
+            .take(len)
+            .map(|item| item.unwrap().0)
+            .collect()
+    }
+
     pub fn insert(&mut self, key: T) {
         let capacity = self.queue.capacity();
         if self.last == capacity {
@@ -31,12 +175,17 @@ where
         }
 
         self.last += 1;
-        insert(&mut self.queue, self.last, Some(key));
+        self.sequence += 1;
+        insert(&mut self.queue, self.last, Some((key, self.sequence)));
 
         self.swim(self.last);
     }
 
     pub fn delete(&mut self) -> Option<T> {
+        if self.last == 0 {
+            return None;
+        }
+
         let max = self.queue[1].take();
         self.queue.swap(1, self.last);
         self.last -= 1;
@@ -47,7 +196,7 @@ where
             self.resize(capacity / 2);
         }
 
-        max
+        max.map(|(item, _)| item)
     }
 
     fn swim(&mut self, k: usize) {
@@ -66,28 +215,32 @@ where
         }
     }
 
-    fn compare(&self, a: &T, b: &T) -> bool {
-        if self.is_max {
-            a < b
-        } else {
-            a > b
+    fn compare(&self, a: &(T, u64), b: &(T, u64)) -> bool {
+        match (self.comparator)(&a.0, &b.0) {
+            Ordering::Less => true,
+            Ordering::Equal => self.stable && a.1 > b.1,
+            Ordering::Greater => false,
         }
     }
 
+    fn compare_at(&self, a: usize, b: usize) -> bool {
+        self.compare(
+            self.queue[a].as_ref().unwrap(),
+            self.queue[b].as_ref().unwrap(),
+        )
+    }
+
     fn sink(&mut self, k: usize) {
         let mut k = k;
 
         while 2 * k <= self.last {
             let mut j = 2 * k;
-            let parent = self.queue[k].as_ref();
-            let l_child = self.queue[j].as_ref();
-            let r_child = self.queue[j + 1].as_ref();
 
-            if j < self.last && self.compare(l_child.unwrap(), r_child.unwrap()) {
+            if j < self.last && self.compare_at(j, j + 1) {
                 j += 1;
             }
 
-            if self.compare(parent.unwrap(), self.queue[j].as_ref().unwrap()) {
+            if self.compare_at(k, j) {
                 self.queue.swap(k, j);
                 k = j;
             } else {
@@ -96,6 +249,12 @@ where
         }
     }
 
+    fn heapify(&mut self) {
+        for k in (1..=self.last / 2).rev() {
+            self.sink(k);
+        }
+    }
+
     fn resize(&mut self, capacity: usize) {
         let mut new_queue = Vec::with_capacity(capacity);
 
@@ -107,6 +266,28 @@ where
     }
 }
 
+impl<'a, T, F> Iterator for Drain<'a, T, F>
+where
+    F: Fn(&T, &T) -> Ordering,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.delete()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.0.len(), Some(self.0.len()))
+    }
+}
+
+impl<'a, T, F> Drop for Drain<'a, T, F> {
+    fn drop(&mut self) {
+        self.0.queue.truncate(1);
+        self.0.last = 0;
+    }
+}
+
 fn insert<T>(v: &mut Vec<Option<T>>, i: usize, item: Option<T>) {
     if v.len() <= i {
         v.insert(i, item);
@@ -195,4 +376,192 @@ mod tests {
 
         assert!(queue.is_empty());
     }
+
+    #[test]
+    fn priority_queue_should_order_items_by_comparator_and_key() {
+        let jobs = vec![
+            ("build", 3, 1),
+            ("lint", 1, 1),
+            ("test", 3, 5),
+            ("docs", 7, 9),
+        ];
+
+        let mut queue = PriorityQueue::new_by(|a: &(&str, u32, u32), b: &(&str, u32, u32)| {
+            b.1.cmp(&a.1).then(a.2.cmp(&b.2))
+        });
+
+        for job in jobs.iter() {
+            queue.insert(*job);
+        }
+
+        assert!(queue.delete().unwrap().0 == "lint");
+        assert!(queue.delete().unwrap().0 == "test");
+        assert!(queue.delete().unwrap().0 == "build");
+        assert!(queue.delete().unwrap().0 == "docs");
+        assert!(queue.is_empty());
+
+        let mut queue = PriorityQueue::new_by_key(|word: &&str| word.len());
+
+        for word in "a quick brown fox jumped".split(' ') {
+            queue.insert(word);
+        }
+
+        assert!(queue.delete() == Some("jumped"));
+        assert!(queue.delete().unwrap().len() == 5);
+        assert!(queue.delete().unwrap().len() == 5);
+        assert!(queue.delete() == Some("fox"));
+        assert!(queue.delete() == Some("a"));
+    }
+
+    #[test]
+    fn heapified_priority_queue_should_behave_like_one_built_by_insertion() {
+        let items: Vec<isize> = (0..200).map(|i| (i * 7919) % 211 - 100).collect();
+        let mut sorted = items.clone();
+        sorted.sort();
+
+        let mut queue = PriorityQueue::from_vec(items.clone(), false);
+
+        assert!(queue.len() == 200);
+        assert!(queue.peek() == Some(&sorted[0]));
+
+        for v in sorted.iter().take(100) {
+            assert!(queue.delete() == Some(*v));
+        }
+
+        for v in sorted.iter().take(100) {
+            queue.insert(*v);
+        }
+
+        assert!(queue.drain().collect::<Vec<_>>() == sorted);
+        assert!(queue.is_empty());
+        assert!(queue.delete() == None);
+        assert!(queue.peek() == None);
+
+        let queue: PriorityQueue<isize> = items.into_iter().collect();
+
+        assert!(queue.peek() == sorted.last());
+        assert!(queue.into_sorted_vec() == sorted);
+    }
+
+    #[test]
+    fn clear_and_partial_drain_should_empty_the_priority_queue() {
+        let mut queue = PriorityQueue::from_vec(vec![3, 1, 4, 1, 5, 9, 2, 6], true);
+
+        {
+            let mut drain = queue.drain();
+
+            assert!(drain.size_hint() == (8, Some(8)));
+            assert!(drain.next() == Some(9));
+            assert!(drain.next() == Some(6));
+        }
+
+        assert!(queue.is_empty());
+
+        queue.insert(2);
+        queue.insert(7);
+
+        assert!(queue.len() == 2);
+
+        queue.clear();
+
+        assert!(queue.is_empty());
+        assert!(queue.delete() == None);
+
+        queue.insert(1);
+
+        assert!(queue.peek() == Some(&1));
+        assert!(PriorityQueue::<u8>::from_vec(vec![], true)
+            .into_sorted_vec()
+            .is_empty());
+        assert!(PriorityQueue::from_vec(vec![2, 3, 1], false).into_sorted_vec() == vec![3, 2, 1]);
+    }
+
+    fn by_priority(is_max: bool) -> impl Fn(&(u32, usize), &(u32, usize)) -> std::cmp::Ordering {
+        move |a, b| {
+            if is_max {
+                a.0.cmp(&b.0)
+            } else {
+                b.0.cmp(&a.0)
+            }
+        }
+    }
+
+    fn assert_fifo_among_equals(deleted: &[(u32, usize)], is_max: bool) {
+        for pair in deleted.windows(2) {
+            let (a, b) = (pair[0], pair[1]);
+
+            if a.0 == b.0 {
+                assert!(a.1 < b.1);
+            } else {
+                assert!((a.0 > b.0) == is_max);
+            }
+        }
+    }
+
+    #[test]
+    fn stable_priority_queue_should_keep_insertion_order_among_equal_keys() {
+        for is_max in [true, false].iter() {
+            for n in [1, 2, 3, 7, 8, 9, 64, 65, 300].iter() {
+                let items: Vec<(u32, usize)> = (0..*n).map(|i| ((i * 7 % 5) as u32, i)).collect();
+
+                let mut inserted = PriorityQueue::new_by(by_priority(*is_max)).stable();
+
+                for item in items.iter() {
+                    inserted.insert(*item);
+                }
+
+                let built =
+                    PriorityQueue::from_vec_by(items.clone(), by_priority(*is_max)).stable();
+
+                for mut queue in vec![inserted, built] {
+                    assert!(queue.is_stable());
+                    assert!(queue.len() == *n);
+
+                    // Shrinking and regrowing the storage must not disturb ties.
+                    let mut deleted: Vec<_> = (0..n / 2).map(|_| queue.delete().unwrap()).collect();
+
+                    assert_fifo_among_equals(&deleted, *is_max);
+
+                    for item in deleted.drain(..) {
+                        queue.insert((item.0, item.1 + 1000));
+                    }
+
+                    let deleted: Vec<_> = queue.drain().collect();
+
+                    assert!(deleted.len() == *n);
+                    assert_fifo_among_equals(&deleted, *is_max);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn stable_mode_should_apply_to_items_already_in_the_queue() {
+        let items: Vec<(u32, usize)> = (0..50).map(|i| ((i % 3) as u32, i)).collect();
+
+        let mut queue = PriorityQueue::new_by(by_priority(false));
+        let mut other = PriorityQueue::new_by(by_priority(false));
+
+        for item in items.iter() {
+            if item.1 < 25 {
+                queue.insert(*item);
+            } else {
+                other.insert(*item);
+            }
+        }
+
+        let mut queue = queue.stable();
+        queue.meld(other);
+
+        let deleted: Vec<_> = queue.drain().collect();
+
+        assert_fifo_among_equals(&deleted, false);
+
+        let queue = PriorityQueue::from_vec_by(items, by_priority(true)).stable();
+        let sorted = queue.into_sorted_vec();
+
+        for pair in sorted.windows(2) {
+            assert!(pair[0].0 < pair[1].0 || pair[0].1 > pair[1].1);
+        }
+    }
 }
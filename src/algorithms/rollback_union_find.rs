@@ -0,0 +1,148 @@
+// Union by size without path compression, so that every union only changes
+// one parent link and one size and can be undone in O(1). Finds take
+// O(log n).
+pub struct RollbackUnionFind {
+    tree: Vec<usize>,
+    sizes: Vec<usize>,
+    count: usize,
+    log: Vec<usize>,
+}
+
+// A point in the union history to roll back to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Snapshot(usize);
+
+impl RollbackUnionFind {
+    pub fn new(num_elts: usize) -> Self {
+        RollbackUnionFind {
+            tree: (0..num_elts).collect(),
+            sizes: vec![1; num_elts],
+            count: num_elts,
+            log: Vec::new(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.tree.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.tree.len()
+    }
+
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    pub fn find(&self, p: usize) -> usize {
+        let mut p = p;
+
+        while p != self.tree[p] {
+            p = self.tree[p];
+        }
+
+        p
+    }
+
+    pub fn connected(&self, p: usize, q: usize) -> bool {
+        self.find(p) == self.find(q)
+    }
+
+    pub fn union(&mut self, p: usize, q: usize) {
+        let mut root_p = self.find(p);
+        let mut root_q = self.find(q);
+
+        if root_p == root_q {
+            return;
+        }
+
+        if self.sizes[root_p] < self.sizes[root_q] {
+            std::mem::swap(&mut root_p, &mut root_q);
+        }
+
+        self.tree[root_q] = root_p;
+        self.sizes[root_p] += self.sizes[root_q];
+        self.count -= 1;
+
+        self.log.push(root_q);
+    }
+
+    pub fn snapshot(&self) -> Snapshot {
+        Snapshot(self.log.len())
+    }
+
+    // Undoes every union made since the snapshot was taken.
+    pub fn rollback(&mut self, to: Snapshot) {
+        assert!(to.0 <= self.log.len(), "snapshot was already rolled back");
+
+        while self.log.len() > to.0 {
+            let child = self.log.pop().unwrap();
+            let root = self.tree[child];
+
+            self.sizes[root] -= self.sizes[child];
+            self.tree[child] = child;
+            self.count += 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::algorithms::rollback_union_find::RollbackUnionFind;
+
+    #[test]
+    fn rollback_should_restore_the_components_at_the_snapshot() {
+        let mut set = RollbackUnionFind::new(8);
+
+        set.union(0, 1);
+        set.union(2, 3);
+
+        let first = set.snapshot();
+
+        set.union(1, 3);
+        set.union(1, 2);
+        set.union(4, 5);
+
+        let second = set.snapshot();
+
+        set.union(5, 0);
+        set.union(6, 7);
+
+        assert!(set.count() == 2);
+        assert!(set.connected(4, 3));
+
+        set.rollback(second);
+
+        assert!(set.count() == 4);
+        assert!(set.connected(0, 3));
+        assert!(set.connected(4, 5));
+        assert!(!set.connected(4, 3));
+        assert!(!set.connected(6, 7));
+
+        set.rollback(first);
+
+        assert!(set.count() == 6);
+        assert!(set.connected(0, 1));
+        assert!(set.connected(2, 3));
+        assert!(!set.connected(0, 3));
+        assert!(!set.connected(4, 5));
+
+        set.rollback(set.snapshot());
+
+        assert!(set.count() == 6);
+    }
+
+    #[test]
+    #[should_panic]
+    fn rolling_back_to_a_discarded_snapshot_should_panic() {
+        let mut set = RollbackUnionFind::new(4);
+        let start = set.snapshot();
+
+        set.union(0, 1);
+
+        let later = set.snapshot();
+
+        set.rollback(start);
+        set.rollback(later);
+    }
+}
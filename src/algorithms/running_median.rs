@@ -0,0 +1,274 @@
+use crate::algorithms::index_priority_queue::{IndexMaxPQ, IndexMinPQ};
+use crate::algorithms::priority_queue::PriorityQueue;
+
+// Tracks the q-quantile of a stream by the nearest-rank method: the lower
+// max-queue holds the ceil(q * n) smallest items and the upper min-queue the
+// rest, so the quantile is always the top of the lower queue.
+pub struct RunningQuantile<T> {
+    lower: PriorityQueue<T>,
+    upper: PriorityQueue<T>,
+    q: f64,
+}
+
+pub struct RunningMedian<T>(RunningQuantile<T>);
+
+// Same split as RunningQuantile over the last `window` items. Items are keyed
+// by their slot in the window so expired ones can be deleted from whichever
+// queue holds them.
+pub struct SlidingQuantile<T> {
+    lower: IndexMaxPQ<T>,
+    upper: IndexMinPQ<T>,
+    window: usize,
+    seen: usize,
+    q: f64,
+}
+
+impl<T: Ord> RunningQuantile<T> {
+    pub fn new(q: f64) -> Self {
+        assert!((0.0..=1.0).contains(&q), "quantile must be within [0, 1]");
+
+        RunningQuantile {
+            lower: PriorityQueue::new(true),
+            upper: PriorityQueue::new(false),
+            q,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.lower.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.lower.len() + self.upper.len()
+    }
+
+    pub fn insert(&mut self, item: T) {
+        match self.lower.peek() {
+            Some(top) if item <= *top => self.lower.insert(item),
+            _ => self.upper.insert(item),
+        }
+
+        let target = rank(self.q, self.len());
+
+        while self.lower.len() > target {
+            let item = self.lower.delete().unwrap();
+            self.upper.insert(item);
+        }
+
+        while self.lower.len() < target {
+            let item = self.upper.delete().unwrap();
+            self.lower.insert(item);
+        }
+    }
+
+    pub fn quantile(&self) -> Option<&T> {
+        self.lower.peek()
+    }
+}
+
+impl<T: Ord> RunningMedian<T> {
+    pub fn new() -> Self {
+        RunningMedian(RunningQuantile::new(0.5))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn insert(&mut self, item: T) {
+        self.0.insert(item)
+    }
+
+    pub fn low_median(&self) -> Option<&T> {
+        self.0.lower.peek()
+    }
+
+    pub fn high_median(&self) -> Option<&T> {
+        if self.0.upper.len() == self.0.lower.len() {
+            self.0.upper.peek()
+        } else {
+            self.0.lower.peek()
+        }
+    }
+}
+
+impl<T: Ord + Copy + Into<f64>> RunningMedian<T> {
+    pub fn median(&self) -> Option<f64> {
+        let low = (*self.low_median()?).into();
+        let high = (*self.high_median()?).into();
+
+        Some((low + high) / 2.0)
+    }
+}
+
+impl<T: Ord> Default for RunningMedian<T> {
+    fn default() -> Self {
+        RunningMedian::new()
+    }
+}
+
+impl<T: Ord> SlidingQuantile<T> {
+    pub fn new(window: usize, q: f64) -> Self {
+        assert!(window > 0, "window must not be empty");
+        assert!((0.0..=1.0).contains(&q), "quantile must be within [0, 1]");
+
+        SlidingQuantile {
+            lower: IndexMaxPQ::new(window),
+            upper: IndexMinPQ::new(window),
+            window,
+            seen: 0,
+            q,
+        }
+    }
+
+    // Tracks the low median of the window.
+    pub fn median(window: usize) -> Self {
+        SlidingQuantile::new(window, 0.5)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.lower.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.lower.len() + self.upper.len()
+    }
+
+    pub fn window(&self) -> usize {
+        self.window
+    }
+
+    // Adds an item and evicts the one that falls out of the window, if any.
+    pub fn insert(&mut self, item: T) -> Option<T> {
+        let slot = self.seen % self.window;
+        let expired = self.lower.delete(slot).or_else(|| self.upper.delete(slot));
+
+        match self.lower.max_key() {
+            Some(top) if item <= *top => self.lower.insert(slot, item),
+            _ => self.upper.insert(slot, item),
+        }
+
+        self.seen += 1;
+
+        let target = rank(self.q, self.len());
+
+        while self.lower.len() > target {
+            let (i, item) = self.lower.delete_max().unwrap();
+            self.upper.insert(i, item);
+        }
+
+        while self.lower.len() < target {
+            let (i, item) = self.upper.delete_min().unwrap();
+            self.lower.insert(i, item);
+        }
+
+        expired
+    }
+
+    pub fn quantile(&self) -> Option<&T> {
+        self.lower.max_key()
+    }
+}
+
+fn rank(q: f64, n: usize) -> usize {
+    if n == 0 {
+        0
+    } else {
+        ((q * n as f64).ceil() as usize).max(1).min(n)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::algorithms::running_median::{RunningMedian, RunningQuantile, SlidingQuantile};
+
+    fn nearest_rank(items: &[i32], q: f64) -> i32 {
+        let mut sorted = items.to_vec();
+        sorted.sort();
+
+        let rank = ((q * sorted.len() as f64).ceil() as usize).max(1);
+
+        sorted[rank - 1]
+    }
+
+    #[test]
+    fn running_median_should_track_the_middle_of_the_stream() {
+        let mut median = RunningMedian::new();
+
+        assert!(median.is_empty());
+        assert!(median.median() == None);
+
+        median.insert(5);
+
+        assert!(median.median() == Some(5.0));
+
+        median.insert(1);
+
+        assert!(median.low_median() == Some(&1));
+        assert!(median.high_median() == Some(&5));
+        assert!(median.median() == Some(3.0));
+
+        median.insert(10);
+
+        assert!(median.median() == Some(5.0));
+
+        median.insert(7);
+        median.insert(-3);
+        median.insert(8);
+
+        assert!(median.len() == 6);
+        assert!(median.low_median() == Some(&5));
+        assert!(median.high_median() == Some(&7));
+        assert!(median.median() == Some(6.0));
+    }
+
+    #[test]
+    fn running_quantile_should_match_nearest_rank_definition() {
+        let items: Vec<i32> = (0..300).map(|i| (i * 7919) % 311 - 150).collect();
+
+        for q in [0.0, 0.1, 0.25, 0.5, 0.9, 0.99, 1.0].iter() {
+            let mut quantile = RunningQuantile::new(*q);
+
+            for (n, v) in items.iter().enumerate() {
+                quantile.insert(*v);
+
+                assert!(*quantile.quantile().unwrap() == nearest_rank(&items[..=n], *q));
+            }
+        }
+    }
+
+    #[test]
+    fn sliding_quantile_should_only_consider_the_last_window_items() {
+        let items: Vec<i32> = (0..300).map(|i| (i * 7919) % 311 - 150).collect();
+
+        for window in [1, 2, 7, 32].iter() {
+            for q in [0.5, 0.95].iter() {
+                let mut quantile = SlidingQuantile::new(*window, *q);
+
+                assert!(quantile.quantile() == None);
+
+                for (n, v) in items.iter().enumerate() {
+                    let expired = quantile.insert(*v);
+                    let start = (n + 1).saturating_sub(*window);
+
+                    assert!(expired == n.checked_sub(*window).map(|i| items[i]));
+                    assert!(quantile.len() == n + 1 - start);
+                    assert!(*quantile.quantile().unwrap() == nearest_rank(&items[start..=n], *q));
+                }
+            }
+        }
+
+        let mut median = SlidingQuantile::median(3);
+
+        for v in [1, 9, 5, 2, 8].iter() {
+            median.insert(*v);
+        }
+
+        assert!(median.window() == 3);
+        assert!(median.quantile() == Some(&5));
+    }
+}
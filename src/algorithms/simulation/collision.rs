@@ -0,0 +1,333 @@
+use crate::algorithms::simulation::Scheduler;
+
+// Event-driven simulation of hard discs colliding elastically inside the unit
+// box. Only the next collision of every particle is predicted; a prediction is
+// stale once one of its particles has been in another collision since.
+pub struct CollisionSystem {
+    particles: Vec<Particle>,
+    scheduler: Scheduler<Collision>,
+    time: f64,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Particle {
+    rx: f64,
+    ry: f64,
+    vx: f64,
+    vy: f64,
+    radius: f64,
+    mass: f64,
+    count: usize,
+}
+
+enum Collision {
+    Particles(usize, usize, usize, usize),
+    VerticalWall(usize, usize),
+    HorizontalWall(usize, usize),
+}
+
+impl Particle {
+    pub fn new(rx: f64, ry: f64, vx: f64, vy: f64, radius: f64, mass: f64) -> Self {
+        Particle {
+            rx,
+            ry,
+            vx,
+            vy,
+            radius,
+            mass,
+            count: 0,
+        }
+    }
+
+    pub fn position(&self) -> (f64, f64) {
+        (self.rx, self.ry)
+    }
+
+    pub fn velocity(&self) -> (f64, f64) {
+        (self.vx, self.vy)
+    }
+
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    pub fn kinetic_energy(&self) -> f64 {
+        0.5 * self.mass * (self.vx * self.vx + self.vy * self.vy)
+    }
+
+    fn advance(&mut self, dt: f64) {
+        self.rx += self.vx * dt;
+        self.ry += self.vy * dt;
+    }
+
+    fn time_to_hit(&self, that: &Particle) -> f64 {
+        let dx = that.rx - self.rx;
+        let dy = that.ry - self.ry;
+        let dvx = that.vx - self.vx;
+        let dvy = that.vy - self.vy;
+        let dvdr = dx * dvx + dy * dvy;
+
+        if dvdr > 0.0 {
+            return f64::INFINITY;
+        }
+
+        let dvdv = dvx * dvx + dvy * dvy;
+
+        if dvdv == 0.0 {
+            return f64::INFINITY;
+        }
+
+        let drdr = dx * dx + dy * dy;
+        let sigma = self.radius + that.radius;
+        let d = dvdr * dvdr - dvdv * (drdr - sigma * sigma);
+
+        if d < 0.0 {
+            return f64::INFINITY;
+        }
+
+        let t = -(dvdr + d.sqrt()) / dvdv;
+
+        if t <= 0.0 {
+            f64::INFINITY
+        } else {
+            t
+        }
+    }
+
+    fn time_to_hit_vertical_wall(&self) -> f64 {
+        if self.vx > 0.0 {
+            (1.0 - self.rx - self.radius) / self.vx
+        } else if self.vx < 0.0 {
+            (self.radius - self.rx) / self.vx
+        } else {
+            f64::INFINITY
+        }
+    }
+
+    fn time_to_hit_horizontal_wall(&self) -> f64 {
+        if self.vy > 0.0 {
+            (1.0 - self.ry - self.radius) / self.vy
+        } else if self.vy < 0.0 {
+            (self.radius - self.ry) / self.vy
+        } else {
+            f64::INFINITY
+        }
+    }
+
+    fn bounce_off(&mut self, that: &mut Particle) {
+        let dx = that.rx - self.rx;
+        let dy = that.ry - self.ry;
+        let dvx = that.vx - self.vx;
+        let dvy = that.vy - self.vy;
+        let dvdr = dx * dvx + dy * dvy;
+        let dist = self.radius + that.radius;
+
+        let magnitude = 2.0 * self.mass * that.mass * dvdr / ((self.mass + that.mass) * dist);
+        let fx = magnitude * dx / dist;
+        let fy = magnitude * dy / dist;
+
+        self.vx += fx / self.mass;
+        self.vy += fy / self.mass;
+        that.vx -= fx / that.mass;
+        that.vy -= fy / that.mass;
+
+        self.count += 1;
+        that.count += 1;
+    }
+
+    fn bounce_off_vertical_wall(&mut self) {
+        self.vx = -self.vx;
+        self.count += 1;
+    }
+
+    fn bounce_off_horizontal_wall(&mut self) {
+        self.vy = -self.vy;
+        self.count += 1;
+    }
+}
+
+impl CollisionSystem {
+    pub fn new(particles: Vec<Particle>) -> Self {
+        CollisionSystem {
+            particles,
+            scheduler: Scheduler::new(),
+            time: 0.0,
+        }
+    }
+
+    pub fn particles(&self) -> &[Particle] {
+        &self.particles
+    }
+
+    pub fn time(&self) -> f64 {
+        self.time
+    }
+
+    pub fn kinetic_energy(&self) -> f64 {
+        self.particles.iter().map(Particle::kinetic_energy).sum()
+    }
+
+    // Runs the simulation until `limit` and returns the number of collisions.
+    pub fn simulate(&mut self, limit: f64) -> usize {
+        assert!(limit >= self.time, "cannot simulate back in time");
+
+        self.scheduler.clear();
+
+        for i in 0..self.particles.len() {
+            predict(&mut self.scheduler, &self.particles, i, limit);
+        }
+
+        let particles = &mut self.particles;
+        let time = &mut self.time;
+        let mut collisions = 0;
+
+        self.scheduler.run_until(limit, |scheduler, collision| {
+            let valid = match collision {
+                Collision::Particles(a, b, count_a, count_b) => {
+                    particles[a].count == count_a && particles[b].count == count_b
+                }
+                Collision::VerticalWall(a, count) | Collision::HorizontalWall(a, count) => {
+                    particles[a].count == count
+                }
+            };
+
+            if !valid {
+                return;
+            }
+
+            for particle in particles.iter_mut() {
+                particle.advance(scheduler.now() - *time);
+            }
+
+            *time = scheduler.now();
+            collisions += 1;
+
+            match collision {
+                Collision::Particles(a, b, _, _) => {
+                    let (low, high) = particles.split_at_mut(a.max(b));
+                    low[a.min(b)].bounce_off(&mut high[0]);
+
+                    predict(scheduler, particles, a, limit);
+                    predict(scheduler, particles, b, limit);
+                }
+                Collision::VerticalWall(a, _) => {
+                    particles[a].bounce_off_vertical_wall();
+                    predict(scheduler, particles, a, limit);
+                }
+                Collision::HorizontalWall(a, _) => {
+                    particles[a].bounce_off_horizontal_wall();
+                    predict(scheduler, particles, a, limit);
+                }
+            }
+        });
+
+        for particle in self.particles.iter_mut() {
+            particle.advance(limit - self.time);
+        }
+
+        self.time = limit;
+
+        collisions
+    }
+}
+
+fn predict(scheduler: &mut Scheduler<Collision>, particles: &[Particle], a: usize, limit: f64) {
+    let now = scheduler.now();
+    let particle = &particles[a];
+
+    for (b, other) in particles.iter().enumerate() {
+        if b == a {
+            continue;
+        }
+
+        let dt = particle.time_to_hit(other);
+
+        if now + dt <= limit {
+            let collision = Collision::Particles(a, b, particle.count, other.count);
+            scheduler.schedule_at(now + dt, collision);
+        }
+    }
+
+    // Round-off can leave a particle a hair past a wall it just bounced off.
+    let dt = particle.time_to_hit_vertical_wall().max(0.0);
+
+    if now + dt <= limit {
+        scheduler.schedule_at(now + dt, Collision::VerticalWall(a, particle.count));
+    }
+
+    let dt = particle.time_to_hit_horizontal_wall().max(0.0);
+
+    if now + dt <= limit {
+        scheduler.schedule_at(now + dt, Collision::HorizontalWall(a, particle.count));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::algorithms::simulation::collision::{CollisionSystem, Particle};
+
+    #[test]
+    fn head_on_collision_of_equal_masses_should_exchange_velocities() {
+        let mut system = CollisionSystem::new(vec![
+            Particle::new(0.25, 0.5, 0.5, 0.0, 0.05, 1.0),
+            Particle::new(0.75, 0.5, -0.5, 0.0, 0.05, 1.0),
+        ]);
+
+        assert!(system.simulate(0.5) == 1);
+
+        let (a, b) = (&system.particles()[0], &system.particles()[1]);
+
+        assert!((a.velocity().0 + 0.5).abs() < 1e-9);
+        assert!((b.velocity().0 - 0.5).abs() < 1e-9);
+        assert!((a.position().0 - 0.4).abs() < 1e-9);
+        assert!(a.count() == 1 && b.count() == 1);
+        assert!(system.time() == 0.5);
+    }
+
+    #[test]
+    #[should_panic]
+    fn simulating_to_an_earlier_time_should_panic() {
+        let mut system = CollisionSystem::new(vec![Particle::new(0.5, 0.5, 0.1, 0.0, 0.05, 1.0)]);
+
+        system.simulate(5.0);
+        system.simulate(3.0);
+    }
+
+    #[test]
+    fn particles_should_stay_in_the_box_and_conserve_energy() {
+        let mut particles = Vec::new();
+
+        for i in 0..5 {
+            for j in 0..5 {
+                let vx = ((i * 7 + j * 3) % 11) as f64 / 10.0 - 0.5;
+                let vy = ((i * 5 + j * 9) % 13) as f64 / 12.0 - 0.5;
+                let mass = 1.0 + ((i + j) % 3) as f64;
+
+                particles.push(Particle::new(
+                    0.1 + 0.2 * i as f64,
+                    0.1 + 0.2 * j as f64,
+                    vx,
+                    vy,
+                    0.02,
+                    mass,
+                ));
+            }
+        }
+
+        let mut system = CollisionSystem::new(particles);
+        let energy = system.kinetic_energy();
+
+        let first = system.simulate(5.0);
+        let second = system.simulate(10.0);
+
+        assert!(first > 0 && second > 0);
+        assert!((system.kinetic_energy() - energy).abs() < 1e-9 * energy);
+
+        for particle in system.particles() {
+            let (x, y) = particle.position();
+
+            assert!(x > -1e-9 && x < 1.0 + 1e-9);
+            assert!(y > -1e-9 && y < 1.0 + 1e-9);
+        }
+    }
+}
@@ -0,0 +1,290 @@
+use std::cmp::Ordering;
+use std::collections::HashSet;
+
+use crate::algorithms::priority_queue::PriorityQueue;
+
+pub mod collision;
+
+// Discrete event scheduler over simulated time. The queue runs in stable mode,
+// so events scheduled for the same time fire in the order they were
+// scheduled. Cancelled events stay in the queue and are skipped when they
+// reach the top.
+pub struct Scheduler<E> {
+    queue: PriorityQueue<Scheduled<E>>,
+    pending: HashSet<u64>,
+    now: f64,
+    ids: u64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct EventHandle(u64);
+
+struct Scheduled<E> {
+    time: f64,
+    id: u64,
+    repeat: Option<Repeat<E>>,
+    event: E,
+}
+
+// Periodic events are cloned through a function pointer so that only
+// schedule_periodic needs `E: Clone`.
+struct Repeat<E> {
+    period: f64,
+    clone: fn(&E) -> E,
+}
+
+impl<E> Scheduler<E> {
+    pub fn new() -> Self {
+        let queue: PriorityQueue<Scheduled<E>> = PriorityQueue::new_by(earliest_first);
+
+        Scheduler {
+            queue: queue.stable(),
+            pending: HashSet::new(),
+            now: 0.0,
+            ids: 0,
+        }
+    }
+
+    pub fn now(&self) -> f64 {
+        self.now
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.pending.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.pending.len()
+    }
+
+    pub fn schedule_at(&mut self, time: f64, event: E) -> EventHandle {
+        self.push(time, None, event)
+    }
+
+    pub fn schedule_in(&mut self, delay: f64, event: E) -> EventHandle {
+        self.push(self.now + delay, None, event)
+    }
+
+    // Fires at `first` and every `period` after that until cancelled.
+    pub fn schedule_periodic(&mut self, first: f64, period: f64, event: E) -> EventHandle
+    where
+        E: Clone,
+    {
+        assert!(period > 0.0, "period must be positive");
+
+        let repeat = Repeat {
+            period,
+            clone: E::clone,
+        };
+
+        self.push(first, Some(repeat), event)
+    }
+
+    pub fn is_pending(&self, handle: EventHandle) -> bool {
+        self.pending.contains(&handle.0)
+    }
+
+    pub fn cancel(&mut self, handle: EventHandle) -> bool {
+        self.pending.remove(&handle.0)
+    }
+
+    pub fn clear(&mut self) {
+        self.queue.clear();
+        self.pending.clear();
+    }
+
+    pub fn peek_time(&mut self) -> Option<f64> {
+        while let Some(top) = self.queue.peek() {
+            if self.pending.contains(&top.id) {
+                return Some(top.time);
+            }
+
+            self.queue.delete();
+        }
+
+        None
+    }
+
+    // Advances the clock to the next event and returns it.
+    pub fn next_event(&mut self) -> Option<(f64, E)> {
+        loop {
+            let scheduled = self.queue.delete()?;
+
+            if !self.pending.contains(&scheduled.id) {
+                continue;
+            }
+
+            self.now = scheduled.time;
+
+            match scheduled.repeat {
+                None => {
+                    self.pending.remove(&scheduled.id);
+                }
+                Some(ref repeat) => {
+                    let next = Scheduled {
+                        time: scheduled.time + repeat.period,
+                        id: scheduled.id,
+                        repeat: Some(Repeat {
+                            period: repeat.period,
+                            clone: repeat.clone,
+                        }),
+                        event: (repeat.clone)(&scheduled.event),
+                    };
+
+                    self.queue.insert(next);
+                }
+            }
+
+            return Some((scheduled.time, scheduled.event));
+        }
+    }
+
+    // Hands every event up to and including `end` to `handler`, which may
+    // schedule further events, then moves the clock to `end`. Returns the
+    // number of events handled.
+    pub fn run_until<H>(&mut self, end: f64, mut handler: H) -> usize
+    where
+        H: FnMut(&mut Self, E),
+    {
+        let mut handled = 0;
+
+        while let Some(time) = self.peek_time() {
+            if time > end {
+                break;
+            }
+
+            let (_, event) = self.next_event().unwrap();
+            handler(self, event);
+            handled += 1;
+        }
+
+        if self.now < end {
+            self.now = end;
+        }
+
+        handled
+    }
+
+    fn push(&mut self, time: f64, repeat: Option<Repeat<E>>, event: E) -> EventHandle {
+        assert!(time >= self.now, "events cannot be scheduled in the past");
+
+        let id = self.ids;
+        self.ids += 1;
+
+        let scheduled = Scheduled {
+            time,
+            id,
+            repeat,
+            event,
+        };
+
+        self.queue.insert(scheduled);
+        self.pending.insert(id);
+
+        EventHandle(id)
+    }
+}
+
+impl<E> Default for Scheduler<E> {
+    fn default() -> Self {
+        Scheduler::new()
+    }
+}
+
+fn earliest_first<E>(a: &Scheduled<E>, b: &Scheduled<E>) -> Ordering {
+    b.time.total_cmp(&a.time)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::algorithms::simulation::Scheduler;
+
+    #[test]
+    fn events_should_fire_in_time_order_and_fifo_among_ties() {
+        let mut scheduler = Scheduler::new();
+
+        assert!(scheduler.is_empty());
+        assert!(scheduler.next_event() == None);
+
+        scheduler.schedule_at(2.0, "c");
+        scheduler.schedule_at(1.0, "a");
+
+        for name in ["d", "e", "f", "g", "h"].iter() {
+            scheduler.schedule_at(2.0, *name);
+        }
+
+        scheduler.schedule_in(1.0, "b");
+
+        assert!(scheduler.len() == 8);
+        assert!(scheduler.peek_time() == Some(1.0));
+
+        let mut order = Vec::new();
+
+        while let Some((time, name)) = scheduler.next_event() {
+            assert!(scheduler.now() == time);
+
+            order.push(name);
+        }
+
+        assert!(order == vec!["a", "b", "c", "d", "e", "f", "g", "h"]);
+        assert!(scheduler.now() == 2.0);
+    }
+
+    #[test]
+    fn cancelled_events_should_never_fire() {
+        let mut scheduler = Scheduler::new();
+
+        let first = scheduler.schedule_at(1.0, 1);
+        let second = scheduler.schedule_at(2.0, 2);
+        scheduler.schedule_at(3.0, 3);
+
+        assert!(scheduler.cancel(first));
+        assert!(!scheduler.cancel(first));
+        assert!(!scheduler.is_pending(first));
+        assert!(scheduler.is_pending(second));
+        assert!(scheduler.len() == 2);
+        assert!(scheduler.peek_time() == Some(2.0));
+        assert!(scheduler.next_event() == Some((2.0, 2)));
+        assert!(!scheduler.cancel(second));
+
+        scheduler.clear();
+
+        assert!(scheduler.is_empty());
+        assert!(scheduler.next_event() == None);
+    }
+
+    #[test]
+    fn periodic_events_should_repeat_until_cancelled() {
+        let mut scheduler = Scheduler::new();
+        let mut fired = Vec::new();
+
+        let tick = scheduler.schedule_periodic(0.0, 1.5, "tick");
+        scheduler.schedule_at(3.0, "stop");
+
+        let handled = scheduler.run_until(10.0, |scheduler, event| {
+            fired.push((scheduler.now(), event));
+
+            if event == "stop" {
+                scheduler.cancel(tick);
+                scheduler.schedule_in(0.5, "done");
+            }
+        });
+
+        // The tick due at 3.0 was rescheduled after "stop", so it is cancelled
+        // before it can fire.
+        assert!(handled == 4);
+        assert!(fired == vec![(0.0, "tick"), (1.5, "tick"), (3.0, "stop"), (3.5, "done")]);
+        assert!(scheduler.now() == 10.0);
+        assert!(scheduler.is_empty());
+    }
+
+    #[test]
+    #[should_panic]
+    fn scheduling_in_the_past_should_panic() {
+        let mut scheduler = Scheduler::new();
+
+        scheduler.schedule_at(1.0, ());
+        scheduler.run_until(5.0, |_, _| {});
+        scheduler.schedule_at(4.0, ());
+    }
+}
@@ -0,0 +1,370 @@
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::iter::{FromIterator, Rev};
+use std::mem;
+use std::slice;
+
+#[derive(Clone)]
+pub struct SmallStack<T, const N: usize> {
+    storage: Storage<T, N>,
+    len: usize,
+}
+
+#[derive(Clone)]
+enum Storage<T, const N: usize> {
+    Inline([Option<T>; N]),
+    Heap(Vec<T>),
+}
+
+pub struct ConsumerIter<T, const N: usize>(SmallStack<T, N>);
+
+pub struct Iter<'a, T>(IterKind<'a, T>);
+
+pub struct IterMut<'a, T>(IterMutKind<'a, T>);
+
+enum IterKind<'a, T> {
+    Inline(Rev<slice::Iter<'a, Option<T>>>),
+    Heap(Rev<slice::Iter<'a, T>>),
+}
+
+enum IterMutKind<'a, T> {
+    Inline(Rev<slice::IterMut<'a, Option<T>>>),
+    Heap(Rev<slice::IterMut<'a, T>>),
+}
+
+impl<T, const N: usize> SmallStack<T, N> {
+    pub fn new() -> Self {
+        SmallStack {
+            storage: Storage::Inline(std::array::from_fn(|_| None)),
+            len: 0,
+        }
+    }
+
+    pub fn with_capacity(capacity: usize) -> Self {
+        if capacity <= N {
+            SmallStack::new()
+        } else {
+            SmallStack {
+                storage: Storage::Heap(Vec::with_capacity(capacity)),
+                len: 0,
+            }
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn spilled(&self) -> bool {
+        match self.storage {
+            Storage::Inline(_) => false,
+            Storage::Heap(_) => true,
+        }
+    }
+
+    pub fn push(&mut self, item: T) {
+        match &mut self.storage {
+            Storage::Inline(items) if self.len < N => items[self.len] = Some(item),
+            Storage::Inline(items) => {
+                let mut heap = Vec::with_capacity(2 * N.max(1));
+
+                for slot in items.iter_mut() {
+                    heap.push(slot.take().unwrap());
+                }
+
+                heap.push(item);
+                self.storage = Storage::Heap(heap);
+            }
+            Storage::Heap(items) => items.push(item),
+        }
+
+        self.len += 1;
+    }
+
+    pub fn pop(&mut self) -> Option<T> {
+        if self.len == 0 {
+            return None;
+        }
+
+        self.len -= 1;
+
+        match &mut self.storage {
+            Storage::Inline(items) => items[self.len].take(),
+            Storage::Heap(items) => items.pop(),
+        }
+    }
+
+    pub fn peek(&self) -> Option<&T> {
+        if self.len == 0 {
+            return None;
+        }
+
+        match &self.storage {
+            Storage::Inline(items) => items[self.len - 1].as_ref(),
+            Storage::Heap(items) => items.last(),
+        }
+    }
+
+    pub fn peek_mut(&mut self) -> Option<&mut T> {
+        if self.len == 0 {
+            return None;
+        }
+
+        match &mut self.storage {
+            Storage::Inline(items) => items[self.len - 1].as_mut(),
+            Storage::Heap(items) => items.last_mut(),
+        }
+    }
+
+    pub fn clear(&mut self) {
+        match &mut self.storage {
+            Storage::Inline(items) => {
+                for slot in items.iter_mut() {
+                    *slot = None;
+                }
+            }
+            Storage::Heap(items) => items.clear(),
+        }
+
+        self.len = 0;
+    }
+
+    pub fn iter(&self) -> Iter<'_, T> {
+        match &self.storage {
+            Storage::Inline(items) => Iter(IterKind::Inline(items[..self.len].iter().rev())),
+            Storage::Heap(items) => Iter(IterKind::Heap(items.iter().rev())),
+        }
+    }
+
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        let len = self.len;
+
+        match &mut self.storage {
+            Storage::Inline(items) => IterMut(IterMutKind::Inline(items[..len].iter_mut().rev())),
+            Storage::Heap(items) => IterMut(IterMutKind::Heap(items.iter_mut().rev())),
+        }
+    }
+
+    pub fn into_vec(self) -> Vec<T> {
+        match self.storage {
+            Storage::Inline(items) => IntoIterator::into_iter(items).flatten().collect(),
+            Storage::Heap(items) => items,
+        }
+    }
+
+    pub fn shrink_to_inline(&mut self) {
+        if self.len > N {
+            return;
+        }
+
+        if let Storage::Heap(items) = &mut self.storage {
+            let mut inline: [Option<T>; N] = std::array::from_fn(|_| None);
+
+            for (slot, item) in inline.iter_mut().zip(mem::take(items)) {
+                *slot = Some(item);
+            }
+
+            self.storage = Storage::Inline(inline);
+        }
+    }
+}
+
+impl<T, const N: usize> Default for SmallStack<T, N> {
+    fn default() -> Self {
+        SmallStack::new()
+    }
+}
+
+impl<T: fmt::Debug, const N: usize> fmt::Debug for SmallStack<T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T: PartialEq, const N: usize> PartialEq for SmallStack<T, N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len() == other.len() && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: Eq, const N: usize> Eq for SmallStack<T, N> {}
+
+impl<T: Hash, const N: usize> Hash for SmallStack<T, N> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.len().hash(state);
+
+        for item in self.iter() {
+            item.hash(state);
+        }
+    }
+}
+
+impl<T, const N: usize> FromIterator<T> for SmallStack<T, N> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut stack = SmallStack::new();
+        stack.extend(iter);
+
+        stack
+    }
+}
+
+impl<T, const N: usize> Extend<T> for SmallStack<T, N> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for item in iter {
+            self.push(item);
+        }
+    }
+}
+
+impl<T, const N: usize> IntoIterator for SmallStack<T, N> {
+    type Item = T;
+    type IntoIter = ConsumerIter<T, N>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        ConsumerIter(self)
+    }
+}
+
+impl<'a, T, const N: usize> IntoIterator for &'a SmallStack<T, N> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T, const N: usize> IntoIterator for &'a mut SmallStack<T, N> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+impl<T, const N: usize> Iterator for ConsumerIter<T, N> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.pop()
+    }
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match &mut self.0 {
+            IterKind::Inline(items) => items.next().and_then(|item| item.as_ref()),
+            IterKind::Heap(items) => items.next(),
+        }
+    }
+}
+
+impl<'a, T> Iterator for IterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match &mut self.0 {
+            IterMutKind::Inline(items) => items.next().and_then(|item| item.as_mut()),
+            IterMutKind::Heap(items) => items.next(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::algorithms::small_stack::SmallStack;
+
+    #[test]
+    fn interface_operations_should_work_as_expected() {
+        let mut stack = SmallStack::<u32, 4>::new();
+
+        assert!(stack.is_empty());
+        assert!(stack.pop() == None);
+        assert!(stack.peek() == None);
+
+        stack.push(0);
+
+        assert!(stack.pop() == Some(0));
+
+        for i in 0..4 {
+            stack.push(i);
+        }
+
+        assert!(!stack.spilled());
+        assert!(stack.peek() == Some(&3));
+
+        stack.push(4);
+
+        assert!(stack.spilled());
+        assert!(stack.len() == 5);
+
+        *stack.peek_mut().unwrap() = 40;
+
+        assert!(stack.pop() == Some(40));
+
+        for i in 0..4 {
+            assert!(stack.pop() == Some(3 - i));
+        }
+
+        assert!(stack.is_empty());
+
+        for i in 0..99 {
+            stack.push(i);
+        }
+
+        for i in 0..99 {
+            assert!(stack.pop() == Some(98 - i));
+        }
+
+        stack.push(1);
+        stack.shrink_to_inline();
+
+        assert!(!stack.spilled());
+        assert!(stack.pop() == Some(1));
+
+        stack.push(2);
+        stack.clear();
+
+        assert!(stack.is_empty());
+        assert!(SmallStack::<u32, 4>::with_capacity(16).spilled());
+    }
+
+    #[test]
+    fn iterators_should_match_array_stack_order() {
+        let mut inline: SmallStack<u32, 8> = (0..5).collect();
+        let mut spilled: SmallStack<u32, 2> = (0..5).collect();
+
+        assert!(inline.iter().eq(spilled.iter()));
+        assert_eq!(format!("{:?}", inline), "[4, 3, 2, 1, 0]");
+        assert_eq!(format!("{:?}", spilled), "[4, 3, 2, 1, 0]");
+
+        for v in &mut inline {
+            *v += 1;
+        }
+
+        for v in spilled.iter_mut() {
+            *v += 1;
+        }
+
+        assert!(inline.iter().eq(&spilled));
+        assert_eq!(inline.clone(), inline);
+        assert_eq!(spilled.clone(), spilled);
+
+        let mut k = 5;
+
+        for v in spilled {
+            assert!(v == k);
+
+            k -= 1;
+        }
+
+        assert!(inline.into_vec() == vec![1, 2, 3, 4, 5]);
+    }
+}
@@ -1,9 +1,10 @@
+use crate::algorithms::adjacency::Adjacency;
 use crate::algorithms::linked_list_queue::LinkedQueue;
 use crate::algorithms::linked_list_stack::LinkedStack;
 
-struct UGraph {
+struct UGraph<A = Vec<LinkedStack<usize>>> {
     num_vertices: usize,
-    edges: Vec<LinkedStack<usize>>,
+    edges: A,
 }
 
 struct DFSPaths {
@@ -26,34 +27,38 @@ struct ConnectedComponents {
 
 impl UGraph {
     pub fn new(num_vertices: usize) -> Self {
-        let mut edges = Vec::with_capacity(num_vertices);
+        UGraph::with_adjacency(num_vertices)
+    }
 
-        for v in 0..num_vertices {
-            edges.insert(v, LinkedStack::new());
-        }
+    pub fn get_adj_edges<'a>(&'a self, v: usize) -> &'a LinkedStack<usize> {
+        &self.edges[v]
+    }
 
+    pub fn get_adj_edges_mut<'a>(&'a mut self, v: usize) -> &'a mut LinkedStack<usize> {
+        &mut self.edges[v]
+    }
+}
+
+impl<A: Adjacency> UGraph<A> {
+    pub fn with_adjacency(num_vertices: usize) -> Self {
         UGraph {
             num_vertices,
-            edges,
+            edges: A::with_vertices(num_vertices),
         }
     }
 
     pub fn add_edge(&mut self, v: usize, w: usize) {
-        self.edges[v].push(w);
-        self.edges[w].push(v);
+        self.edges.push(v, w);
+        self.edges.push(w, v);
     }
 
-    pub fn get_adj_edges<'a>(&'a self, v: usize) -> &'a LinkedStack<usize> {
-        &self.edges[v]
-    }
-
-    pub fn get_adj_edges_mut<'a>(&'a mut self, v: usize) -> &'a mut LinkedStack<usize> {
-        &mut self.edges[v]
+    pub fn adj(&self, v: usize) -> A::Iter<'_> {
+        self.edges.iter(v)
     }
 }
 
 impl DFSPaths {
-    pub fn new(g: &UGraph, origin: usize) -> Self {
+    pub fn new<A: Adjacency>(g: &UGraph<A>, origin: usize) -> Self {
         let mut paths = DFSPaths {
             marked: vec![false; g.num_vertices],
             edge_to: vec![None; g.num_vertices],
@@ -87,10 +92,10 @@ impl DFSPaths {
         }
     }
 
-    fn dfs(&mut self, g: &UGraph, v: usize) {
+    fn dfs<A: Adjacency>(&mut self, g: &UGraph<A>, v: usize) {
         self.marked[v] = true;
 
-        for w in g.get_adj_edges(v).iter() {
+        for w in g.adj(v) {
             if !self.marked[*w] {
                 self.dfs(g, *w);
                 self.edge_to[*w] = Some(v);
@@ -100,7 +105,7 @@ impl DFSPaths {
 }
 
 impl BFSPaths {
-    pub fn new(g: &UGraph, origin: usize) -> Self {
+    pub fn new<A: Adjacency>(g: &UGraph<A>, origin: usize) -> Self {
         let mut paths = BFSPaths {
             marked: vec![false; g.num_vertices],
             edge_to: vec![None; g.num_vertices],
@@ -134,7 +139,7 @@ impl BFSPaths {
         }
     }
 
-    fn bfs(&mut self, g: &UGraph, v: usize) {
+    fn bfs<A: Adjacency>(&mut self, g: &UGraph<A>, v: usize) {
         let mut q = LinkedQueue::new();
 
         q.enqueue(self.origin);
@@ -143,7 +148,7 @@ impl BFSPaths {
         while !q.is_empty() {
             let v = q.dequeue().unwrap();
 
-            for w in g.get_adj_edges(v).iter() {
+            for w in g.adj(v) {
                 if !self.marked[*w] {
                     q.enqueue(*w);
                     self.marked[*w] = true;
@@ -155,7 +160,7 @@ impl BFSPaths {
 }
 
 impl ConnectedComponents {
-    pub fn new(g: &UGraph) -> Self {
+    pub fn new<A: Adjacency>(g: &UGraph<A>) -> Self {
         let mut cc = ConnectedComponents {
             marked: vec![false; g.num_vertices],
             id: vec![None; g.num_vertices],
@@ -176,11 +181,11 @@ impl ConnectedComponents {
         self.id[v]
     }
 
-    fn dfs(&mut self, g: &UGraph, v: usize) {
+    fn dfs<A: Adjacency>(&mut self, g: &UGraph<A>, v: usize) {
         self.marked[v] = true;
         self.id[v] = Some(self.count);
 
-        for w in g.get_adj_edges(v).iter() {
+        for w in g.adj(v) {
             if !self.marked[*w] {
                 self.dfs(g, *w);
             }
@@ -190,6 +195,7 @@ impl ConnectedComponents {
 
 #[cfg(test)]
 mod tests {
+    use crate::algorithms::adjacency::ArenaAdjacency;
     use crate::algorithms::linked_list_stack::LinkedStack;
     use crate::algorithms::undirected_graph::{BFSPaths, ConnectedComponents, DFSPaths, UGraph};
 
@@ -311,4 +317,29 @@ mod tests {
         assert!(cc.id(14) == Some(3));
         assert!(cc.id(16) == Some(3));
     }
+
+    #[test]
+    fn arena_adjacency_should_behave_like_linked_adjacency() {
+        let mut g = UGraph::new(10);
+        let mut ag = UGraph::<ArenaAdjacency>::with_adjacency(10);
+
+        for (v, w) in &[(0, 1), (0, 2), (0, 6), (6, 4), (4, 5), (5, 3), (7, 8)] {
+            g.add_edge(*v, *w);
+            ag.add_edge(*v, *w);
+        }
+
+        for v in 0..10 {
+            assert!(g.adj(v).eq(ag.adj(v)));
+        }
+
+        let paths = BFSPaths::new(&g, 0);
+        let arena_paths = BFSPaths::new(&ag, 0);
+
+        for v in 0..10 {
+            assert!(paths.has_path_to(v) == arena_paths.has_path_to(v));
+        }
+
+        assert!(paths.path_to(3) == arena_paths.path_to(3));
+        assert!(ConnectedComponents::new(&ag).count == 3);
+    }
 }
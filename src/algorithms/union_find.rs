@@ -0,0 +1,205 @@
+use crate::algorithms::connectivity_quick_find;
+use crate::algorithms::connectivity_quick_union;
+use crate::algorithms::connectivity_quick_union_improved;
+use crate::algorithms::rollback_union_find::RollbackUnionFind;
+
+// Dynamic connectivity over the elements 0..len. `find` returns the canonical
+// element of a component, which only stays the same until the next union.
+pub trait UnionFind {
+    fn len(&self) -> usize;
+
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn count(&self) -> usize;
+
+    fn find(&self, p: usize) -> usize;
+
+    fn connected(&self, p: usize, q: usize) -> bool {
+        self.find(p) == self.find(q)
+    }
+
+    fn union(&mut self, p: usize, q: usize);
+}
+
+impl UnionFind for connectivity_quick_find::Network {
+    fn len(&self) -> usize {
+        connectivity_quick_find::Network::len(self)
+    }
+
+    fn count(&self) -> usize {
+        connectivity_quick_find::Network::count(self)
+    }
+
+    fn find(&self, p: usize) -> usize {
+        connectivity_quick_find::Network::find(self, p)
+    }
+
+    fn connected(&self, p: usize, q: usize) -> bool {
+        connectivity_quick_find::Network::connected(self, p, q)
+    }
+
+    fn union(&mut self, p: usize, q: usize) {
+        connectivity_quick_find::Network::union(self, p, q)
+    }
+}
+
+impl UnionFind for connectivity_quick_union::Network {
+    fn len(&self) -> usize {
+        connectivity_quick_union::Network::len(self)
+    }
+
+    fn count(&self) -> usize {
+        connectivity_quick_union::Network::count(self)
+    }
+
+    fn find(&self, p: usize) -> usize {
+        connectivity_quick_union::Network::find(self, p)
+    }
+
+    fn connected(&self, p: usize, q: usize) -> bool {
+        connectivity_quick_union::Network::connected(self, p, q)
+    }
+
+    fn union(&mut self, p: usize, q: usize) {
+        connectivity_quick_union::Network::union(self, p, q)
+    }
+}
+
+impl UnionFind for connectivity_quick_union_improved::Network {
+    fn len(&self) -> usize {
+        connectivity_quick_union_improved::Network::len(self)
+    }
+
+    fn count(&self) -> usize {
+        connectivity_quick_union_improved::Network::count(self)
+    }
+
+    fn find(&self, p: usize) -> usize {
+        connectivity_quick_union_improved::Network::find(self, p)
+    }
+
+    fn connected(&self, p: usize, q: usize) -> bool {
+        connectivity_quick_union_improved::Network::connected(self, p, q)
+    }
+
+    fn union(&mut self, p: usize, q: usize) {
+        connectivity_quick_union_improved::Network::union(self, p, q)
+    }
+}
+
+impl UnionFind for RollbackUnionFind {
+    fn len(&self) -> usize {
+        RollbackUnionFind::len(self)
+    }
+
+    fn count(&self) -> usize {
+        RollbackUnionFind::count(self)
+    }
+
+    fn find(&self, p: usize) -> usize {
+        RollbackUnionFind::find(self, p)
+    }
+
+    fn connected(&self, p: usize, q: usize) -> bool {
+        RollbackUnionFind::connected(self, p, q)
+    }
+
+    fn union(&mut self, p: usize, q: usize) {
+        RollbackUnionFind::union(self, p, q)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    use crate::algorithms::connectivity_quick_find;
+    use crate::algorithms::connectivity_quick_union;
+    use crate::algorithms::connectivity_quick_union_improved;
+    use crate::algorithms::rollback_union_find::RollbackUnionFind;
+    use crate::algorithms::union_find::UnionFind;
+
+    fn networks(n: usize) -> Vec<Box<dyn UnionFind>> {
+        vec![
+            Box::new(connectivity_quick_find::Network::new(n)),
+            Box::new(connectivity_quick_union::Network::new(n)),
+            Box::new(connectivity_quick_union_improved::Network::new(n)),
+            Box::new(RollbackUnionFind::new(n)),
+        ]
+    }
+
+    #[test]
+    fn all_networks_should_start_with_singleton_components() {
+        for network in networks(10) {
+            assert!(network.len() == 10);
+            assert!(network.count() == 10);
+            assert!(!network.is_empty());
+
+            for p in 0..10 {
+                assert!(network.find(p) == p);
+                assert!(network.connected(p, p));
+            }
+        }
+
+        for network in networks(0) {
+            assert!(network.is_empty());
+            assert!(network.count() == 0);
+        }
+    }
+
+    #[test]
+    fn all_networks_should_agree_on_identical_operation_sequences() {
+        let n = 200;
+        let mut rng = StdRng::seed_from_u64(46);
+        let mut networks = networks(n);
+
+        for _ in 0..2000 {
+            let p = rng.gen_range(0, n);
+            let q = rng.gen_range(0, n);
+
+            if rng.gen_bool(0.3) {
+                for network in networks.iter_mut() {
+                    network.union(p, q);
+                }
+            }
+
+            let connected = networks[0].connected(p, q);
+            let count = networks[0].count();
+
+            for network in networks.iter() {
+                assert!(network.connected(p, q) == connected);
+                assert!(network.count() == count);
+                assert!(network.connected(network.find(p), p));
+            }
+        }
+
+        for network in networks.iter_mut() {
+            for p in 1..n {
+                network.union(p - 1, p);
+            }
+        }
+
+        for network in networks.iter() {
+            assert!(network.count() == 1);
+            assert!((1..n).all(|p| network.find(p) == network.find(0)));
+        }
+    }
+
+    #[test]
+    fn redundant_unions_should_not_change_the_component_count() {
+        for mut network in networks(6) {
+            network.union(0, 1);
+            network.union(1, 0);
+            network.union(2, 3);
+            network.union(0, 2);
+            network.union(3, 1);
+
+            assert!(network.count() == 3);
+            assert!(network.connected(1, 3));
+            assert!(!network.connected(4, 5));
+        }
+    }
+}